@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the string with each ASCII uppercase letter (`A` to `Z`) mapped to lowercase.
+    /// All other bytes, including the bytes of non-ASCII code points, are unchanged.
+    ///
+    /// This is ASCII-only, so that the cost is a constant number of constraints per byte.
+    pub fn to_lowercase_ascii(&self) -> Self {
+        self.map_ascii_case(true)
+    }
+
+    /// Returns the string with each ASCII lowercase letter (`a` to `z`) mapped to uppercase.
+    /// All other bytes, including the bytes of non-ASCII code points, are unchanged.
+    ///
+    /// This is ASCII-only, so that the cost is a constant number of constraints per byte.
+    pub fn to_uppercase_ascii(&self) -> Self {
+        self.map_ascii_case(false)
+    }
+
+    /// Returns the string with the case of each ASCII letter mapped to lowercase or uppercase.
+    fn map_ascii_case(&self, to_lowercase: bool) -> Self {
+        let bytes = self
+            .bytes
+            .iter()
+            .map(|byte| {
+                let mut bits = byte.to_bits_le();
+                let [b0, b1, b2, b3, b4, b5, b6, b7] = &bits[..] else { E::halt("Expected a byte to have 8 bits") };
+                // The ASCII letters are of the form `010xxxxx` (uppercase) or `011xxxxx` (lowercase),
+                // where the low 5 bits `xxxxx` are within 1 to 26, i.e. non-zero and not within 27 to 31.
+                let is_nonzero = b0 | b1 | b2 | b3 | b4;
+                let is_above_26 = b4 & b3 & (b2 | (b1 & b0));
+                let is_letter = !b7 & b6 & is_nonzero & !is_above_26;
+                // Set (lowercase) or clear (uppercase) the case bit `b5` of each letter.
+                bits[5] = match to_lowercase {
+                    true => b5 | is_letter,
+                    false => b5 & !is_letter,
+                };
+                U8::from_bits_le(&bits)
+            })
+            .collect();
+        StringType { mode: self.mode, bytes, size_in_bytes: self.size_in_bytes.clone() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn check_ascii_case(
+        mode: Mode,
+        string: &str,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let string = StringType::<Circuit>::new(mode, console::StringType::new(string));
+        let expected = string.eject_value();
+
+        Circuit::scope(format!("{mode} lowercase"), || {
+            let candidate = string.to_lowercase_ascii();
+            assert_eq!(expected.to_ascii_lowercase(), *candidate.eject_value());
+            assert_scope!(num_constants, num_public, num_private, num_constraints);
+        });
+        Circuit::scope(format!("{mode} uppercase"), || {
+            let candidate = string.to_uppercase_ascii();
+            assert_eq!(expected.to_ascii_uppercase(), *candidate.eject_value());
+            assert_scope!(num_constants, num_public, num_private, num_constraints);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_ascii_case_constant() {
+        check_ascii_case(Mode::Constant, "Hello, World! @[`{ AZaz 09", 0, 0, 0, 0);
+        check_ascii_case(Mode::Constant, "\u{c9}t\u{e9} \u{3a3}", 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_ascii_case_public() {
+        check_ascii_case(Mode::Public, "Hello, World! @[`{ AZaz 09", 0, 0, 312, 312);
+        check_ascii_case(Mode::Public, "\u{c9}t\u{e9} \u{3a3}", 0, 0, 96, 96);
+    }
+
+    #[test]
+    fn test_ascii_case_private() {
+        check_ascii_case(Mode::Private, "Hello, World! @[`{ AZaz 09", 0, 0, 312, 312);
+        check_ascii_case(Mode::Private, "\u{c9}t\u{e9} \u{3a3}", 0, 0, 96, 96);
+    }
+}
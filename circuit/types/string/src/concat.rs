@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the concatenation of `self` and `other`.
+    /// Halts if the concatenation exceeds the maximum string size in bytes.
+    pub fn concat(&self, other: &Self) -> Self {
+        // Ensure the concatenation is within the allowed capacity.
+        let num_bytes = self.bytes.len() + other.bytes.len();
+        if num_bytes > E::MAX_STRING_BYTES as usize {
+            E::halt(format!("Attempted to concatenate strings into a string of size {num_bytes}"))
+        }
+
+        StringType {
+            mode: Mode::combine(self.mode, [other.mode]),
+            bytes: self.bytes.iter().chain(&other.bytes).cloned().collect(),
+            size_in_bytes: &self.size_in_bytes + &other.size_in_bytes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn check_concat(mode_a: Mode, mode_b: Mode, first: &str, second: &str) {
+        let string_a = StringType::<Circuit>::new(mode_a, console::StringType::new(first));
+        let string_b = StringType::<Circuit>::new(mode_b, console::StringType::new(second));
+        let expected = console::StringType::<<Circuit as Environment>::Network>::new(&format!("{first}{second}"));
+
+        Circuit::scope(format!("{mode_a} {mode_b}"), || {
+            let candidate = string_a.concat(&string_b);
+            assert_eq!(expected, candidate.eject_value());
+            // Ensure the size in bytes is consistent with the bytes.
+            assert_eq!(console::Field::from_u32(expected.len() as u32), candidate.size_in_bytes.eject_value());
+            // Ensure the concatenation is free.
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_concat() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                check_concat(mode_a, mode_b, "hello ", "world");
+                check_concat(mode_a, mode_b, "", "\u{e9}t\u{e9}");
+                check_concat(mode_a, mode_b, "abc", "");
+            }
+        }
+    }
+
+    #[test]
+    fn test_concat_exceeds_capacity() {
+        let half = "a".repeat(Circuit::MAX_STRING_BYTES as usize / 2 + 1);
+        let string = StringType::<Circuit>::new(Mode::Private, console::StringType::new(&half));
+        let result = std::panic::catch_unwind(|| string.concat(&string));
+        assert!(result.is_err());
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,160 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns `true` if `needle` occurs in `self`.
+    /// If `needle` is longer than `self`, this returns `false` without emitting constraints.
+    ///
+    /// The needle is compared at every byte offset of the string, so the cost is bounded by
+    /// `(self.len() - needle.len() + 1) * needle.len()` byte comparisons.
+    pub fn contains(&self, needle: &Self) -> Boolean<E> {
+        self.matches_at_each_offset(needle).into_iter().fold(Boolean::constant(false), |acc, is_match| acc | is_match)
+    }
+
+    /// Returns `(true, index)` for the byte index of the first occurrence of `needle` in `self`,
+    /// or `(false, 0)` if `needle` does not occur in `self`.
+    /// If `needle` is longer than `self`, this returns `(false, 0)` without emitting constraints.
+    ///
+    /// The needle is compared at every byte offset of the string, so the cost is bounded by
+    /// `(self.len() - needle.len() + 1) * needle.len()` byte comparisons, plus one selection per offset.
+    pub fn index_of(&self, needle: &Self) -> (Boolean<E>, U32<E>) {
+        self.matches_at_each_offset(needle).into_iter().enumerate().fold(
+            (Boolean::constant(false), U32::constant(console::Integer::new(0))),
+            |(is_found, index), (offset, is_match)| {
+                // Select the current offset, if this is the first occurrence.
+                let is_first = is_match.clone() & !is_found.clone();
+                let offset = U32::constant(console::Integer::new(
+                    u32::try_from(offset).unwrap_or_else(|error| E::halt(error.to_string())),
+                ));
+                (is_found | is_match, U32::ternary(&is_first, &offset, &index))
+            },
+        )
+    }
+
+    /// Returns, for each byte offset of `self` at which `needle` fits, whether `needle` occurs at that offset.
+    pub(super) fn matches_at_each_offset(&self, needle: &Self) -> Vec<Boolean<E>> {
+        // Ensure the needle is not longer than the string.
+        if needle.bytes.len() > self.bytes.len() {
+            return vec![];
+        }
+        // Check that the bytes of the string at each offset are equal to the needle.
+        (0..=self.bytes.len() - needle.bytes.len())
+            .map(|offset| {
+                self.bytes[offset..]
+                    .iter()
+                    .zip(&needle.bytes)
+                    .fold(Boolean::constant(true), |acc, (a, b)| acc & a.is_equal(b))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn check_contains(
+        mode: Mode,
+        string: &str,
+        needle: &str,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let string = StringType::<Circuit>::new(mode, console::StringType::new(string));
+        let expected = string.eject_value().contains(needle);
+        let needle = StringType::<Circuit>::new(mode, console::StringType::new(needle));
+
+        Circuit::scope(format!("{mode}"), || {
+            let candidate = string.contains(&needle);
+            assert_eq!(expected, candidate.eject_value());
+            assert_scope!(num_constants, num_public, num_private, num_constraints);
+        });
+        Circuit::reset();
+    }
+
+    fn check_index_of(
+        mode: Mode,
+        string: &str,
+        needle: &str,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let string = StringType::<Circuit>::new(mode, console::StringType::new(string));
+        let expected = string.eject_value().find(needle);
+        let needle = StringType::<Circuit>::new(mode, console::StringType::new(needle));
+
+        Circuit::scope(format!("{mode}"), || {
+            let (is_found, index) = string.index_of(&needle);
+            assert_eq!(expected.is_some(), is_found.eject_value());
+            assert_eq!(expected.unwrap_or(0) as u32, *index.eject_value());
+            assert_scope!(num_constants, num_public, num_private, num_constraints);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_contains_constant() {
+        check_contains(Mode::Constant, "hello world", "o w", 0, 0, 0, 0);
+        check_contains(Mode::Constant, "hello world", "low", 0, 0, 0, 0);
+        check_contains(Mode::Constant, "hello world", "", 0, 0, 0, 0);
+        check_contains(Mode::Constant, "hello", "hello world", 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_contains_public() {
+        check_contains(Mode::Public, "hello world", "o w", 0, 0, 80, 80);
+        check_contains(Mode::Public, "hello world", "low", 0, 0, 80, 80);
+        check_contains(Mode::Public, "hello world", "", 0, 0, 0, 0);
+        check_contains(Mode::Public, "hello", "hello world", 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_contains_private() {
+        check_contains(Mode::Private, "hello world", "o w", 0, 0, 80, 80);
+        check_contains(Mode::Private, "hello world", "low", 0, 0, 80, 80);
+        check_contains(Mode::Private, "hello world", "", 0, 0, 0, 0);
+        check_contains(Mode::Private, "hello", "hello world", 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_index_of_constant() {
+        check_index_of(Mode::Constant, "hello world", "o", 384, 0, 0, 0);
+        check_index_of(Mode::Constant, "hello world", "low", 320, 0, 0, 0);
+        check_index_of(Mode::Constant, "hello world", "", 416, 0, 0, 0);
+        check_index_of(Mode::Constant, "hello", "hello world", 32, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_index_of_public() {
+        check_index_of(Mode::Public, "hello world", "o", 384, 0, 67, 67);
+        check_index_of(Mode::Public, "hello world", "low", 320, 0, 105, 105);
+        check_index_of(Mode::Public, "hello world", "", 416, 0, 0, 0);
+        check_index_of(Mode::Public, "hello", "hello world", 32, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_index_of_private() {
+        check_index_of(Mode::Private, "hello world", "o", 384, 0, 67, 67);
+        check_index_of(Mode::Private, "hello world", "low", 320, 0, 105, 105);
+        check_index_of(Mode::Private, "hello world", "", 416, 0, 0, 0);
+        check_index_of(Mode::Private, "hello", "hello world", 32, 0, 0, 0);
+    }
+}
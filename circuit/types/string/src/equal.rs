@@ -18,6 +18,13 @@ impl<E: Environment> Equal<Self> for StringType<E> {
     type Output = Boolean<E>;
 
     /// Returns `true` if `self` and `other` are equal.
+    ///
+    /// The sizes in bytes are compared, and then the contents, packed into fields.
+    /// Strings of unequal sizes are unequal, as their sizes differ, even if their packed fields agree.
+    ///
+    /// Note that the size of a string is not hidden by its mode: the number of bytes in a string
+    /// determines the number of constraints, so the size of a private string is leaked through the
+    /// constraint count. This gadget does not pad to a bound, and callers must not rely on it to hide sizes.
     fn is_equal(&self, other: &Self) -> Self::Output {
         // Convert each string type into fields.
         let this = self.to_fields();
@@ -104,6 +111,47 @@ mod tests {
         Ok(())
     }
 
+    fn check_is_equal_unequal_sizes(
+        mode: Mode,
+        first: &str,
+        second: &str,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let string_a = StringType::<Circuit>::new(mode, console::StringType::new(first));
+        let string_b = StringType::<Circuit>::new(mode, console::StringType::new(second));
+
+        Circuit::scope(format!("{mode}"), || {
+            let candidate = string_a.is_equal(&string_b);
+            assert!(!candidate.eject_value());
+            assert_scope!(num_constants, num_public, num_private, num_constraints);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_is_equal_unequal_sizes_constant() {
+        check_is_equal_unequal_sizes(Mode::Constant, "abc", "abcd", 2, 0, 0, 0);
+        check_is_equal_unequal_sizes(Mode::Constant, "abcd", "abc", 2, 0, 0, 0);
+        check_is_equal_unequal_sizes(Mode::Constant, "", "a", 1, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_is_equal_unequal_sizes_public() {
+        check_is_equal_unequal_sizes(Mode::Public, "abc", "abcd", 0, 0, 5, 5);
+        check_is_equal_unequal_sizes(Mode::Public, "abcd", "abc", 0, 0, 5, 5);
+        check_is_equal_unequal_sizes(Mode::Public, "", "a", 0, 0, 2, 2);
+    }
+
+    #[test]
+    fn test_is_equal_unequal_sizes_private() {
+        check_is_equal_unequal_sizes(Mode::Private, "abc", "abcd", 0, 0, 5, 5);
+        check_is_equal_unequal_sizes(Mode::Private, "abcd", "abc", 0, 0, 5, 5);
+        check_is_equal_unequal_sizes(Mode::Private, "", "a", 0, 0, 2, 2);
+    }
+
     #[test]
     fn test_is_equal_constant() -> Result<()> {
         check_is_equal(Mode::Constant, 9, 0, 0, 0)
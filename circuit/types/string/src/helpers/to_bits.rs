@@ -14,6 +14,16 @@
 
 use super::*;
 
+impl<E: Environment> StringType<E> {
+    /// Returns the number of bits in the bit representation of `self`, i.e. 8 bits per byte.
+    ///
+    /// This matches the length of `to_bits_le` and `to_bits_be`, and is fixed by the circuit structure,
+    /// so it may be used to size buffers and loops without adding constraints.
+    pub fn num_bits(&self) -> usize {
+        self.bytes.len() * 8
+    }
+}
+
 impl<E: Environment> ToBits for StringType<E> {
     type Boolean = Boolean<E>;
 
@@ -99,6 +109,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_num_bits() {
+        let rng = &mut TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for string in ["", "a", "\u{e9}t\u{e9}", &rng.next_string(Circuit::MAX_STRING_BYTES / 4, false)] {
+                let candidate = StringType::<Circuit>::new(mode, console::StringType::new(string));
+
+                Circuit::scope(format!("{mode}"), || {
+                    assert_eq!(string.len() * 8, candidate.num_bits());
+                    assert_eq!(candidate.to_bits_le().len(), candidate.num_bits());
+                    assert_eq!(candidate.to_bits_be().len(), candidate.num_bits());
+                    assert_scope!(0, 0, 0, 0);
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
     #[test]
     fn test_string_to_bits_le_constant() {
         check_to_bits_le(Mode::Constant, 0, 0, 0, 0);
@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the size of the string in bytes, as a `U32`.
+    ///
+    /// The size is recovered from the size in bytes of the string, so it has the same mode.
+    /// Note that the size of a private string is already determined by its number of bytes,
+    /// which is public in the circuit structure; this does not reveal anything further.
+    pub fn len(&self) -> U32<E> {
+        U32::from_field(self.size_in_bytes.clone())
+    }
+
+    /// Returns `true` if the string is empty.
+    pub fn is_empty(&self) -> Boolean<E> {
+        self.size_in_bytes.is_zero()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn check_len(
+        mode: Mode,
+        string: &str,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let string = StringType::<Circuit>::new(mode, console::StringType::new(string));
+        let expected = string.eject_value().len();
+
+        Circuit::scope(format!("{mode}"), || {
+            let candidate = string.len();
+            assert_eq!(expected as u32, *candidate.eject_value());
+            assert_scope!(num_constants, num_public, num_private, num_constraints);
+        });
+        Circuit::reset();
+    }
+
+    fn check_is_empty(
+        mode: Mode,
+        string: &str,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let string = StringType::<Circuit>::new(mode, console::StringType::new(string));
+        let expected = string.eject_value().is_empty();
+
+        Circuit::scope(format!("{mode}"), || {
+            let candidate = string.is_empty();
+            assert_eq!(expected, candidate.eject_value());
+            assert_scope!(num_constants, num_public, num_private, num_constraints);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_len_constant() {
+        check_len(Mode::Constant, "hello world", 32, 0, 0, 0);
+        check_len(Mode::Constant, "\u{e9}t\u{e9}", 32, 0, 0, 0);
+        check_len(Mode::Constant, "", 32, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_len_public() {
+        check_len(Mode::Public, "hello world", 0, 0, 32, 33);
+        check_len(Mode::Public, "\u{e9}t\u{e9}", 0, 0, 32, 33);
+        check_len(Mode::Public, "", 0, 0, 32, 33);
+    }
+
+    #[test]
+    fn test_len_private() {
+        check_len(Mode::Private, "hello world", 0, 0, 32, 33);
+        check_len(Mode::Private, "\u{e9}t\u{e9}", 0, 0, 32, 33);
+        check_len(Mode::Private, "", 0, 0, 32, 33);
+    }
+
+    #[test]
+    fn test_is_empty() {
+        check_is_empty(Mode::Constant, "hello world", 1, 0, 0, 0);
+        check_is_empty(Mode::Constant, "", 1, 0, 0, 0);
+        check_is_empty(Mode::Public, "hello world", 0, 0, 2, 2);
+        check_is_empty(Mode::Public, "", 0, 0, 2, 2);
+        check_is_empty(Mode::Private, "hello world", 0, 0, 2, 2);
+        check_is_empty(Mode::Private, "", 0, 0, 2, 2);
+    }
+}
@@ -13,10 +13,20 @@
 // limitations under the License.
 
 #![forbid(unsafe_code)]
+#![allow(clippy::too_many_arguments)]
 #![cfg_attr(test, allow(clippy::assertions_on_result_states))]
 
+mod ascii_case;
+mod concat;
+mod contains;
 mod equal;
 mod helpers;
+mod len;
+mod repeat;
+mod replace;
+mod starts_with;
+mod substring;
+mod trim;
 
 #[cfg(test)]
 use console::TestRng;
@@ -26,8 +36,13 @@ use snarkvm_circuit_environment::assert_scope;
 use snarkvm_circuit_environment::prelude::*;
 use snarkvm_circuit_types_boolean::Boolean;
 use snarkvm_circuit_types_field::Field;
-use snarkvm_circuit_types_integers::U8;
+use snarkvm_circuit_types_integers::{U8, U32};
 
+/// A string in the circuit, stored as its bytes and its size in bytes.
+///
+/// `StringTrait` is an empty marker trait, which is also implemented by the console string type,
+/// and has no access to the bytes of a string. The string gadgets, such as `concat`, `substring`,
+/// and `trim_ascii`, are therefore inherent methods of `StringType`.
 #[derive(Clone)]
 pub struct StringType<E: Environment> {
     mode: Mode,
@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the string repeated `n` times.
+    /// Halts if the result exceeds the maximum string size in bytes.
+    ///
+    /// As `n` is public, the repetition emits no constraints, and its size is the size of the string scaled by `n`.
+    pub fn repeat(&self, n: usize) -> Self {
+        // Ensure the repetition is within the allowed capacity.
+        let num_bytes = match self.bytes.len().checked_mul(n) {
+            Some(num_bytes) if num_bytes <= E::MAX_STRING_BYTES as usize => num_bytes,
+            _ => E::halt(format!("Attempted to repeat a string of size {}, {n} times", self.bytes.len())),
+        };
+
+        // Cast the number of repetitions as a field element.
+        let count = u32::try_from(n).unwrap_or_else(|error| E::halt(error.to_string()));
+        StringType {
+            mode: self.mode,
+            bytes: self.bytes.iter().cycle().take(num_bytes).cloned().collect(),
+            size_in_bytes: &self.size_in_bytes * Field::constant(console::Field::from_u32(count)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn check_repeat(mode: Mode, string: &str, n: usize) {
+        let expected = console::StringType::<<Circuit as Environment>::Network>::new(&string.repeat(n));
+        let string = StringType::<Circuit>::new(mode, console::StringType::new(string));
+
+        Circuit::scope(format!("{mode}"), || {
+            let candidate = string.repeat(n);
+            assert_eq!(expected, candidate.eject_value());
+            // Ensure the size in bytes is consistent with the bytes.
+            assert_eq!(console::Field::from_u32(expected.len() as u32), candidate.size_in_bytes.eject_value());
+            // Ensure the repetition only allocates the number of repetitions as a constant.
+            assert_scope!(1, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_repeat() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_repeat(mode, "ab", 3);
+            check_repeat(mode, "\u{e9}", 4);
+            check_repeat(mode, "hello", 1);
+            check_repeat(mode, "hello", 0);
+            check_repeat(mode, "", 5);
+        }
+    }
+
+    #[test]
+    fn test_repeat_exceeds_capacity() {
+        let string = StringType::<Circuit>::new(Mode::Private, console::StringType::new("ab"));
+        let n = Circuit::MAX_STRING_BYTES as usize / 2 + 1;
+        assert!(std::panic::catch_unwind(|| string.repeat(n)).is_err());
+        assert!(std::panic::catch_unwind(|| string.repeat(usize::MAX)).is_err());
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the string with the first occurrence of `from` replaced by `to`, or the string unchanged
+    /// if `from` does not occur in it. The size of the string is unchanged, so returns an error if `from` and `to`
+    /// differ in size.
+    ///
+    /// The cost is bounded by `(self.len() - from.len() + 1) * from.len()` byte comparisons and byte selections.
+    /// Note that replacing bytes within a code point may produce a string that fails to eject.
+    pub fn replace_first(&self, from: &Self, to: &Self) -> Result<Self> {
+        // Ensure the replacement has the same size as the pattern.
+        if from.bytes.len() != to.bytes.len() {
+            bail!("Attempted to replace {} bytes with {} bytes, which must be equal", from.bytes.len(), to.bytes.len())
+        }
+
+        // Determine whether each offset is the first occurrence of the pattern.
+        let mut is_found = Boolean::constant(false);
+        let is_first = self
+            .matches_at_each_offset(from)
+            .into_iter()
+            .map(|is_match| {
+                let is_first = is_match.clone() & !is_found.clone();
+                is_found = &is_found | &is_match;
+                is_first
+            })
+            .collect::<Vec<_>>();
+
+        // Select each byte from the replacement, if it is within the first occurrence of the pattern.
+        let bytes = self
+            .bytes
+            .iter()
+            .enumerate()
+            .map(|(index, byte)| {
+                is_first.iter().enumerate().fold(byte.clone(), |byte, (offset, is_first)| {
+                    match index.checked_sub(offset).and_then(|position| to.bytes.get(position)) {
+                        Some(replacement) => U8::ternary(is_first, replacement, &byte),
+                        None => byte,
+                    }
+                })
+            })
+            .collect();
+
+        Ok(StringType {
+            mode: Mode::combine(self.mode, [from.mode, to.mode]),
+            bytes,
+            size_in_bytes: self.size_in_bytes.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn check_replace_first(
+        mode: Mode,
+        string: &str,
+        from: &str,
+        to: &str,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let expected = console::StringType::<<Circuit as Environment>::Network>::new(&string.replacen(from, to, 1));
+        let string = StringType::<Circuit>::new(mode, console::StringType::new(string));
+        let from = StringType::<Circuit>::new(mode, console::StringType::new(from));
+        let to = StringType::<Circuit>::new(mode, console::StringType::new(to));
+
+        Circuit::scope(format!("{mode}"), || {
+            let candidate = string.replace_first(&from, &to).unwrap();
+            assert_eq!(expected, candidate.eject_value());
+            assert_scope!(num_constants, num_public, num_private, num_constraints);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_replace_first_constant() {
+        check_replace_first(Mode::Constant, "hello world", "o", "0", 0, 0, 0, 0);
+        check_replace_first(Mode::Constant, "hello world", "world", "there", 0, 0, 0, 0);
+        check_replace_first(Mode::Constant, "hello world", "xyz", "abc", 0, 0, 0, 0);
+        check_replace_first(Mode::Constant, "hello world", "", "", 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_replace_first_public() {
+        check_replace_first(Mode::Public, "hello world", "o", "0", 0, 0, 130, 130);
+        check_replace_first(Mode::Public, "hello world", "world", "there", 0, 0, 390, 390);
+        check_replace_first(Mode::Public, "hello world", "xyz", "abc", 0, 0, 304, 304);
+        check_replace_first(Mode::Public, "hello world", "", "", 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_replace_first_private() {
+        check_replace_first(Mode::Private, "hello world", "o", "0", 0, 0, 130, 130);
+        check_replace_first(Mode::Private, "hello world", "world", "there", 0, 0, 390, 390);
+        check_replace_first(Mode::Private, "hello world", "xyz", "abc", 0, 0, 304, 304);
+        check_replace_first(Mode::Private, "hello world", "", "", 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_replace_first_unequal_sizes() {
+        let string = StringType::<Circuit>::new(Mode::Private, console::StringType::new("hello world"));
+        let from = StringType::<Circuit>::new(Mode::Private, console::StringType::new("world"));
+        let to = StringType::<Circuit>::new(Mode::Private, console::StringType::new("earth!"));
+        assert!(string.replace_first(&from, &to).is_err());
+        assert!(to.replace_first(&to, &from).is_err());
+        // Ensure mismatched sizes do not halt the circuit.
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns `true` if `prefix` is a prefix of `self`.
+    /// If `prefix` is longer than `self`, this returns `false` without emitting constraints.
+    pub fn starts_with(&self, prefix: &Self) -> Boolean<E> {
+        // Ensure the prefix is not longer than the string.
+        if prefix.bytes.len() > self.bytes.len() {
+            return Boolean::constant(false);
+        }
+        // Check that the leading bytes of the string are equal to the prefix.
+        self.bytes.iter().zip(&prefix.bytes).fold(Boolean::constant(true), |acc, (a, b)| acc & a.is_equal(b))
+    }
+
+    /// Returns `true` if `suffix` is a suffix of `self`.
+    /// If `suffix` is longer than `self`, this returns `false` without emitting constraints.
+    pub fn ends_with(&self, suffix: &Self) -> Boolean<E> {
+        // Ensure the suffix is not longer than the string.
+        if suffix.bytes.len() > self.bytes.len() {
+            return Boolean::constant(false);
+        }
+        // Check that the trailing bytes of the string are equal to the suffix.
+        self.bytes
+            .iter()
+            .rev()
+            .zip(suffix.bytes.iter().rev())
+            .fold(Boolean::constant(true), |acc, (a, b)| acc & a.is_equal(b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn check_starts_with(
+        mode: Mode,
+        string: &str,
+        prefix: &str,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let string = StringType::<Circuit>::new(mode, console::StringType::new(string));
+        let expected = string.eject_value().starts_with(prefix);
+        let prefix = StringType::<Circuit>::new(mode, console::StringType::new(prefix));
+
+        Circuit::scope(format!("{mode}"), || {
+            let candidate = string.starts_with(&prefix);
+            assert_eq!(expected, candidate.eject_value());
+            assert_scope!(num_constants, num_public, num_private, num_constraints);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_starts_with_constant() {
+        check_starts_with(Mode::Constant, "hello world", "hello", 0, 0, 0, 0);
+        check_starts_with(Mode::Constant, "hello world", "help", 0, 0, 0, 0);
+        check_starts_with(Mode::Constant, "hello world", "hello world!", 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_starts_with_public() {
+        check_starts_with(Mode::Public, "hello world", "hello", 0, 0, 14, 14);
+        check_starts_with(Mode::Public, "hello world", "help", 0, 0, 11, 11);
+        check_starts_with(Mode::Public, "hello world", "hello world!", 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_starts_with_private() {
+        check_starts_with(Mode::Private, "hello world", "hello", 0, 0, 14, 14);
+        check_starts_with(Mode::Private, "hello world", "help", 0, 0, 11, 11);
+        check_starts_with(Mode::Private, "hello world", "hello world!", 0, 0, 0, 0);
+    }
+
+    fn check_ends_with(
+        mode: Mode,
+        string: &str,
+        suffix: &str,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let string = StringType::<Circuit>::new(mode, console::StringType::new(string));
+        let expected = string.eject_value().ends_with(suffix);
+        let suffix = StringType::<Circuit>::new(mode, console::StringType::new(suffix));
+
+        Circuit::scope(format!("{mode}"), || {
+            let candidate = string.ends_with(&suffix);
+            assert_eq!(expected, candidate.eject_value());
+            assert_scope!(num_constants, num_public, num_private, num_constraints);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_ends_with_constant() {
+        check_ends_with(Mode::Constant, "hello world", "world", 0, 0, 0, 0);
+        check_ends_with(Mode::Constant, "hello world", "word", 0, 0, 0, 0);
+        check_ends_with(Mode::Constant, "hello world", "hello world!", 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_ends_with_public() {
+        check_ends_with(Mode::Public, "hello world", "world", 0, 0, 14, 14);
+        check_ends_with(Mode::Public, "hello world", "word", 0, 0, 11, 11);
+        check_ends_with(Mode::Public, "hello world", "hello world!", 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_ends_with_private() {
+        check_ends_with(Mode::Private, "hello world", "world", 0, 0, 14, 14);
+        check_ends_with(Mode::Private, "hello world", "word", 0, 0, 11, 11);
+        check_ends_with(Mode::Private, "hello world", "hello world!", 0, 0, 0, 0);
+    }
+}
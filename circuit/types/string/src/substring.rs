@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the substring of `len` bytes, starting at the byte index `start`.
+    /// Halts if `start + len` exceeds the size of the string in bytes.
+    ///
+    /// As the indices are public, the substring emits no constraints, and its size is a constant. Note that the indices
+    /// are byte offsets, so they must fall on code point boundaries for the substring to eject.
+    pub fn substring(&self, start: usize, len: usize) -> Self {
+        // Ensure the substring is within the string.
+        let num_bytes = self.bytes.len();
+        match start.checked_add(len) {
+            Some(end) if end <= num_bytes => {
+                // Cast the number of bytes in the substring as a field element.
+                let size = u32::try_from(len).unwrap_or_else(|error| E::halt(error.to_string()));
+                StringType {
+                    mode: self.mode,
+                    bytes: self.bytes[start..end].to_vec(),
+                    size_in_bytes: Field::constant(console::Field::from_u32(size)),
+                }
+            }
+            _ => E::halt(format!("Attempted to take {len} bytes at index {start} of a string of size {num_bytes}")),
+        }
+    }
+
+    /// Returns the prefix and suffix of the string, split at the byte index `index`.
+    /// Returns an error if `index` exceeds the size of the string in bytes.
+    ///
+    /// As the index is public, the split emits no constraints. Note that the index is a byte offset,
+    /// so it must fall on a code point boundary for both halves to eject.
+    pub fn split_at(&self, index: usize) -> Result<(Self, Self)> {
+        // Ensure the index is within the string.
+        let num_bytes = self.bytes.len();
+        ensure!(index <= num_bytes, "Attempted to split a string of size {num_bytes} at index {index}");
+
+        Ok((self.substring(0, index), self.substring(index, num_bytes - index)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn check_substring(mode: Mode, string: &str, start: usize, len: usize) {
+        let expected = console::StringType::<<Circuit as Environment>::Network>::new(&string[start..start + len]);
+        let string = StringType::<Circuit>::new(mode, console::StringType::new(string));
+
+        Circuit::scope(format!("{mode}"), || {
+            let candidate = string.substring(start, len);
+            assert_eq!(expected, candidate.eject_value());
+            // Ensure the size in bytes is consistent with the bytes.
+            assert_eq!(console::Field::from_u32(len as u32), candidate.size_in_bytes.eject_value());
+            // Ensure the substring only allocates its size as a constant.
+            assert_scope!(1, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_substring() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_substring(mode, "hello world", 0, 5);
+            check_substring(mode, "hello world", 6, 5);
+            check_substring(mode, "hello world", 3, 0);
+            check_substring(mode, "hello world", 11, 0);
+            check_substring(mode, "h\u{e9}llo", 1, 2);
+        }
+    }
+
+    fn check_split_at(mode: Mode, string: &str, index: usize) {
+        let (prefix, suffix) = string.split_at(index);
+        let expected_prefix = console::StringType::<<Circuit as Environment>::Network>::new(prefix);
+        let expected_suffix = console::StringType::<<Circuit as Environment>::Network>::new(suffix);
+        let string = StringType::<Circuit>::new(mode, console::StringType::new(string));
+
+        Circuit::scope(format!("{mode}"), || {
+            let (candidate_prefix, candidate_suffix) = string.split_at(index).unwrap();
+            assert_eq!(expected_prefix, candidate_prefix.eject_value());
+            assert_eq!(expected_suffix, candidate_suffix.eject_value());
+            // Ensure the split only allocates the size of each half as a constant.
+            assert_scope!(2, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_split_at() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_split_at(mode, "hello world", 5);
+            check_split_at(mode, "hello world", 0);
+            check_split_at(mode, "hello world", 11);
+            check_split_at(mode, "h\u{e9}llo", 3);
+            check_split_at(mode, "", 0);
+        }
+    }
+
+    #[test]
+    fn test_split_at_out_of_bounds() {
+        let string = StringType::<Circuit>::new(Mode::Private, console::StringType::new("hello"));
+        assert!(string.split_at(6).is_err());
+        assert!(string.split_at(usize::MAX).is_err());
+        // Ensure an out of bounds index does not halt the circuit.
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_substring_out_of_bounds() {
+        let string = StringType::<Circuit>::new(Mode::Private, console::StringType::new("hello"));
+        assert!(std::panic::catch_unwind(|| string.substring(3, 3)).is_err());
+        assert!(std::panic::catch_unwind(|| string.substring(6, 0)).is_err());
+        assert!(std::panic::catch_unwind(|| string.substring(1, usize::MAX)).is_err());
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the string with leading and trailing ASCII whitespace (space, `\t`, `\n`, and `\r`) removed.
+    ///
+    /// The number of bytes to remove is read from the string, and each removed byte is constrained to be
+    /// whitespace, and the first and last remaining bytes are constrained not to be, so the cost is bounded
+    /// by a constant number of constraints per removed byte. The result holds only the remaining bytes, so
+    /// it can be passed to any other string gadget. Note that, as with the size of the string, the number
+    /// of removed bytes is fixed in the circuit structure, and is therefore not hidden.
+    pub fn trim_ascii(&self) -> Self {
+        // Determine the number of leading and trailing whitespace bytes.
+        let is_whitespace_value = |byte: &U8<E>| matches!(*byte.eject_value(), b' ' | b'\t' | b'\n' | b'\r');
+        let start = self.bytes.iter().take_while(|byte| is_whitespace_value(byte)).count();
+        let end =
+            self.bytes.len() - self.bytes[start..].iter().rev().take_while(|byte| is_whitespace_value(byte)).count();
+
+        // Ensure each removed byte is whitespace.
+        for byte in self.bytes[..start].iter().chain(&self.bytes[end..]) {
+            E::assert(Self::is_whitespace_ascii(byte));
+        }
+        // Ensure the first and last remaining bytes are not whitespace.
+        if start < end {
+            E::assert(!Self::is_whitespace_ascii(&self.bytes[start]));
+            E::assert(!Self::is_whitespace_ascii(&self.bytes[end - 1]));
+        }
+
+        // Cast the number of remaining bytes as a field element.
+        let size = u32::try_from(end - start).unwrap_or_else(|error| E::halt(error.to_string()));
+        StringType {
+            mode: self.mode,
+            bytes: self.bytes[start..end].to_vec(),
+            size_in_bytes: Field::constant(console::Field::from_u32(size)),
+        }
+    }
+
+    /// Returns `true` if the given byte is ASCII whitespace, i.e. a space, `\t`, `\n`, or `\r`.
+    fn is_whitespace_ascii(byte: &U8<E>) -> Boolean<E> {
+        [b' ', b'\t', b'\n', b'\r']
+            .into_iter()
+            .map(|whitespace| byte.is_equal(&U8::constant(console::Integer::new(whitespace))))
+            .fold(Boolean::constant(false), |acc, is_equal| acc | is_equal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn check_trim_ascii(
+        mode: Mode,
+        string: &str,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let expected = string.trim_matches(|c| matches!(c, ' ' | '\t' | '\n' | '\r'));
+        let expected = console::StringType::<<Circuit as Environment>::Network>::new(expected);
+        let string = StringType::<Circuit>::new(mode, console::StringType::new(string));
+
+        Circuit::scope(format!("{mode}"), || {
+            let candidate = string.trim_ascii();
+            assert_eq!(expected, candidate.eject_value());
+            // Ensure the size in bytes is consistent with the bytes.
+            assert_eq!(console::Field::from_u32(expected.len() as u32), candidate.size_in_bytes.eject_value());
+            assert_scope!(num_constants, num_public, num_private, num_constraints);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_trim_ascii_constant() {
+        check_trim_ascii(Mode::Constant, " \t hello world\r\n", 225, 0, 0, 0);
+        check_trim_ascii(Mode::Constant, "hello world", 65, 0, 0, 0);
+        check_trim_ascii(Mode::Constant, " \n ", 97, 0, 0, 0);
+        check_trim_ascii(Mode::Constant, "", 1, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_trim_ascii_public() {
+        check_trim_ascii(Mode::Public, " \t hello world\r\n", 225, 0, 77, 84);
+        check_trim_ascii(Mode::Public, "hello world", 65, 0, 22, 24);
+        check_trim_ascii(Mode::Public, " \n ", 97, 0, 33, 36);
+        check_trim_ascii(Mode::Public, "", 1, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_trim_ascii_private() {
+        check_trim_ascii(Mode::Private, " \t hello world\r\n", 225, 0, 77, 84);
+        check_trim_ascii(Mode::Private, "hello world", 65, 0, 22, 24);
+        check_trim_ascii(Mode::Private, " \n ", 97, 0, 33, 36);
+        check_trim_ascii(Mode::Private, "", 1, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_trim_ascii_then_concat_and_ends_with() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let string = StringType::<Circuit>::new(mode, console::StringType::new("  ab  "));
+            let other = StringType::<Circuit>::new(mode, console::StringType::new("cd"));
+
+            // Ensure the trimmed string concatenates without any padding.
+            let candidate = string.trim_ascii().concat(&other);
+            assert_eq!(console::StringType::new("abcd"), candidate.eject_value());
+            assert_eq!(console::Field::from_u32(4), candidate.size_in_bytes.eject_value());
+
+            // Ensure the trimmed string ends with its last remaining bytes.
+            let suffix = StringType::<Circuit>::new(mode, console::StringType::new("ab"));
+            assert!(string.trim_ascii().ends_with(&suffix).eject_value());
+            let suffix = StringType::<Circuit>::new(mode, console::StringType::new("b "));
+            assert!(!string.trim_ascii().ends_with(&suffix).eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
@@ -24,11 +24,7 @@ impl<N: Network> Serialize for ComputeKey<N> {
 impl<'de, N: Network> Deserialize<'de> for ComputeKey<N> {
     /// Deserializes an account compute key from bytes.
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        FromBytesDeserializer::<Self>::deserialize(
-            deserializer,
-            "compute key",
-            2 * ((N::Field::size_in_bits() + 7) / 8),
-        )
+        FromBytesDeserializer::<Self>::deserialize(deserializer, "compute key", 2 * Field::<N>::SIZE_IN_BYTES)
     }
 }
 
@@ -29,11 +29,9 @@ impl<'de, N: Network> Deserialize<'de> for PrivateKey<N> {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         match deserializer.is_human_readable() {
             true => FromStr::from_str(&String::deserialize(deserializer)?).map_err(de::Error::custom),
-            false => FromBytesDeserializer::<Self>::deserialize(
-                deserializer,
-                "private key",
-                (N::Scalar::size_in_bits() + 7) / 8,
-            ),
+            false => {
+                FromBytesDeserializer::<Self>::deserialize(deserializer, "private key", Scalar::<N>::SIZE_IN_BYTES)
+            }
         }
     }
 }
@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>
+    MerkleTree<E, LH, PH, DEPTH>
+{
+    /// Reads in a Merkle tree from a buffer, using the given leaf hasher and path hasher.
+    ///
+    /// As the hashers are not stored in the buffer, they must be the ones used to construct the Merkle tree.
+    /// The internal hashes are recomputed from the stored leaf hashes, and must match the stored root.
+    pub fn read_le_with_hashers<R: Read>(leaf_hasher: &LH, path_hasher: &PH, mut reader: R) -> Result<Self> {
+        // Read the number of leaves.
+        let number_of_leaves = u64::read_le(&mut reader)?;
+        // Ensure the number of leaves is within the tree depth.
+        ensure!(
+            (number_of_leaves as u128) <= (1u128 << DEPTH),
+            "Found {number_of_leaves} Merkle leaves, which exceeds the capacity of a tree of depth {DEPTH}"
+        );
+        // Read the leaf hashes.
+        let leaf_hashes = (0..number_of_leaves).map(|_| Field::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the root.
+        let root = Field::read_le(&mut reader)?;
+
+        // Reconstruct the Merkle tree from the leaf hashes.
+        let merkle_tree = Self::from_leaf_hashes(leaf_hasher, path_hasher, &leaf_hashes)?;
+        // Ensure the recomputed root matches the stored root.
+        ensure!(merkle_tree.root == root, "The stored Merkle root does not match the recomputed Merkle root");
+        Ok(merkle_tree)
+    }
+}
+
+impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8> ToBytes
+    for MerkleTree<E, LH, PH, DEPTH>
+{
+    /// Writes the Merkle tree to a buffer.
+    ///
+    /// Only the leaf hashes and the root are written, as the internal hashes are recomputed when reading.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the number of leaves.
+        (self.number_of_leaves as u64).write_le(&mut writer)?;
+        // Write the leaf hashes.
+        self.leaf_hashes().map_err(error)?.iter().try_for_each(|leaf_hash| leaf_hash.write_le(&mut writer))?;
+        // Write the root.
+        self.root.write_le(&mut writer)
+    }
+}
@@ -0,0 +1,147 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// An append-only Merkle tree, which stores only the rightmost node on each level, instead of the full tree.
+///
+/// After appending a sequence of leaves, the root is identical to that of a `MerkleTree` over the same leaves.
+#[derive(Clone)]
+pub struct MerkleFrontier<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>
+{
+    /// The leaf hasher for the Merkle frontier.
+    leaf_hasher: LH,
+    /// The path hasher for the Merkle frontier.
+    path_hasher: PH,
+    /// The computed root of the Merkle frontier.
+    root: PH::Hash,
+    /// The root of the last full subtree on each level, from the leaf level to the root level.
+    /// The node on level `i` is set only if bit `i` of the number of leaves is set.
+    frontier: Vec<PH::Hash>,
+    /// The hash of an empty subtree on each level, from the leaf level to the root level.
+    empty_hashes: Vec<PH::Hash>,
+    /// The number of hashed leaves in the Merkle frontier.
+    number_of_leaves: u64,
+}
+
+impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>
+    MerkleFrontier<E, LH, PH, DEPTH>
+{
+    /// Initializes a new, empty Merkle frontier.
+    pub fn new(leaf_hasher: &LH, path_hasher: &PH) -> Result<Self> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+
+        // Compute the hash of an empty subtree on each level.
+        let mut empty_hash = path_hasher.hash_empty()?;
+        let mut empty_hashes = Vec::with_capacity(DEPTH as usize + 1);
+        empty_hashes.push(empty_hash);
+        for _ in 0..DEPTH {
+            empty_hash = path_hasher.hash_children(&empty_hash, &empty_hash)?;
+            empty_hashes.push(empty_hash);
+        }
+
+        let mut frontier = Self {
+            leaf_hasher: leaf_hasher.clone(),
+            path_hasher: path_hasher.clone(),
+            root: empty_hashes[0],
+            frontier: vec![empty_hashes[0]; DEPTH as usize + 1],
+            empty_hashes,
+            number_of_leaves: 0,
+        };
+        frontier.root = frontier.compute_root(&frontier.frontier, 0)?;
+        Ok(frontier)
+    }
+
+    /// Appends the given leaf to the Merkle frontier, and updates the root.
+    pub fn append(&mut self, leaf: &LH::Leaf) -> Result<()> {
+        // Ensure the Merkle frontier is not full.
+        ensure!(
+            (self.number_of_leaves as u128) < (1u128 << DEPTH),
+            "Merkle tree cannot exceed depth {DEPTH}: the maximum number of leaves is reached"
+        );
+
+        // Merge the new leaf hash with the full subtrees on its left, from the leaf level upwards.
+        let mut hash = self.leaf_hasher.hash_leaf(leaf)?;
+        let mut level = 0;
+        while (self.number_of_leaves >> level) & 1 == 1 {
+            hash = self.path_hasher.hash_children(&self.frontier[level], &hash)?;
+            level += 1;
+        }
+
+        // Compute the updated frontier and root.
+        let mut frontier = self.frontier.clone();
+        frontier[level] = hash;
+        let root = self.compute_root(&frontier, self.number_of_leaves + 1)?;
+
+        // Update the frontier at the very end, so the original frontier is not altered in case of failure.
+        self.frontier = frontier;
+        self.number_of_leaves += 1;
+        self.root = root;
+        Ok(())
+    }
+
+    /// Returns the root of the Merkle frontier.
+    pub const fn root(&self) -> &PH::Hash {
+        &self.root
+    }
+
+    /// Returns the number of leaves in the Merkle frontier.
+    pub const fn number_of_leaves(&self) -> u64 {
+        self.number_of_leaves
+    }
+
+    /// Returns the root for the given frontier and number of leaves, following the layout of `MerkleTree`: the
+    /// leaves are padded to the next power of two, and the resulting root is hashed with the empty hash up to `DEPTH`.
+    fn compute_root(&self, frontier: &[PH::Hash], number_of_leaves: u64) -> Result<PH::Hash> {
+        // Compute the depth of the tree over the leaves, padded to the next power of two.
+        let tree_depth = match number_of_leaves.max(1).checked_next_power_of_two() {
+            Some(num_leaves) => num_leaves.trailing_zeros() as usize,
+            None => DEPTH as usize,
+        };
+
+        // Compute the root of the tree over the leaves.
+        let mut root = match number_of_leaves {
+            // If there are no leaves, the root is the empty hash.
+            0 => self.empty_hashes[0],
+            // If the tree is full, the root is the last full subtree.
+            num_leaves if num_leaves.is_power_of_two() => frontier[tree_depth],
+            // Otherwise, hash the full subtrees with the empty subtrees on their right, from the leaf level upwards.
+            num_leaves => {
+                let mut hash = None;
+                for (level, (node, empty_hash)) in
+                    frontier.iter().zip_eq(&self.empty_hashes).take(tree_depth).enumerate()
+                {
+                    hash = match ((num_leaves >> level) & 1 == 1, hash) {
+                        (true, hash) => Some(self.path_hasher.hash_children(node, &hash.unwrap_or(*empty_hash))?),
+                        (false, Some(hash)) => Some(self.path_hasher.hash_children(&hash, empty_hash)?),
+                        (false, None) => None,
+                    };
+                }
+                match hash {
+                    Some(hash) => hash,
+                    None => bail!("Failed to compute the root of the Merkle frontier"),
+                }
+            }
+        };
+
+        // Hash the root with the empty hash, for each padded level up to `DEPTH`.
+        for _ in tree_depth..DEPTH as usize {
+            root = self.path_hasher.hash_children(&root, &self.empty_hashes[0])?;
+        }
+        Ok(root)
+    }
+}
@@ -30,6 +30,19 @@ pub trait PathHash: Clone + Send + Sync {
     /// Returns the hash of the given child nodes.
     fn hash_children(&self, left: &Self::Hash, right: &Self::Hash) -> Result<Self::Hash>;
 
+    /// Returns the hash of the given child nodes, where each node hash is `WIDTH` field elements wide.
+    ///
+    /// By default, only node hashes of a single field element are supported, which are hashed with `hash_children`.
+    /// Path hash functions with wider node hashes override this method.
+    fn hash_wide_children<const WIDTH: usize>(
+        &self,
+        left: &[Self::Hash; WIDTH],
+        right: &[Self::Hash; WIDTH],
+    ) -> Result<[Self::Hash; WIDTH]> {
+        ensure!(WIDTH == 1, "This path hash does not support node hashes of {WIDTH} field elements");
+        Ok([self.hash_children(&left[0], &right[0])?; WIDTH])
+    }
+
     /// Returns the hash for each tuple of child nodes.
     fn hash_all_children(&self, child_nodes: &[(Self::Hash, Self::Hash)]) -> Result<Vec<Self::Hash>> {
         match child_nodes.len() {
@@ -12,6 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod bytes;
+
+mod frontier;
+pub use frontier::*;
+
 mod helpers;
 pub use helpers::*;
 
@@ -25,7 +30,10 @@ use snarkvm_console_types::prelude::*;
 
 use aleo_std::prelude::*;
 
-use std::collections::BTreeMap;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    sync::atomic::{AtomicU64, Ordering},
+};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -54,13 +62,29 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
     pub fn new(leaf_hasher: &LH, path_hasher: &PH, leaves: &[LH::Leaf]) -> Result<Self> {
         let timer = timer!("MerkleTree::new");
 
+        // Compute each leaf hash.
+        let leaf_hashes = leaf_hasher.hash_leaves(leaves)?;
+        lap!(timer, "Hashed {} leaves", leaves.len());
+
+        // Construct the Merkle tree from the leaf hashes.
+        let merkle_tree = Self::from_leaf_hashes(leaf_hasher, path_hasher, &leaf_hashes)?;
+
+        finish!(timer);
+        Ok(merkle_tree)
+    }
+
+    #[inline]
+    /// Initializes a new Merkle tree with the given leaf hashes.
+    fn from_leaf_hashes(leaf_hasher: &LH, path_hasher: &PH, leaf_hashes: &[PH::Hash]) -> Result<Self> {
+        let timer = timer!("MerkleTree::from_leaf_hashes");
+
         // Ensure the Merkle tree depth is greater than 0.
         ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
         // Ensure the Merkle tree depth is less than or equal to 64.
         ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
 
         // Compute the maximum number of leaves.
-        let max_leaves = match leaves.len().checked_next_power_of_two() {
+        let max_leaves = match leaf_hashes.len().checked_next_power_of_two() {
             Some(num_leaves) => num_leaves,
             None => bail!("Integer overflow when computing the maximum number of leaves in the Merkle tree"),
         };
@@ -80,15 +104,16 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         // Calculate the size of the tree which excludes leafless nodes.
         // The minimum tree size is either a single root node or the calculated number of nodes plus
         // the supplied leaves; if the number of leaves is odd, an empty hash is added for padding.
-        let minimum_tree_size =
-            std::cmp::max(1, num_nodes + leaves.len() + if leaves.len() > 1 { leaves.len() % 2 } else { 0 });
+        let minimum_tree_size = std::cmp::max(
+            1,
+            num_nodes + leaf_hashes.len() + if leaf_hashes.len() > 1 { leaf_hashes.len() % 2 } else { 0 },
+        );
 
         // Initialize the Merkle tree.
         let mut tree = vec![empty_hash; minimum_tree_size];
 
-        // Compute and store each leaf hash.
-        tree[num_nodes..num_nodes + leaves.len()].copy_from_slice(&leaf_hasher.hash_leaves(leaves)?);
-        lap!(timer, "Hashed {} leaves", leaves.len());
+        // Store each leaf hash.
+        tree[num_nodes..num_nodes + leaf_hashes.len()].copy_from_slice(leaf_hashes);
 
         // Compute and store the hashes for each level, iterating from the penultimate level to the root level.
         let mut start_index = num_nodes;
@@ -133,10 +158,84 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
             root: root_hash,
             tree,
             empty_hash,
-            number_of_leaves: leaves.len(),
+            number_of_leaves: leaf_hashes.len(),
         })
     }
 
+    #[inline]
+    /// Initializes a new Merkle tree with the given leaves, along with a map from each leaf hash
+    /// to every leaf index at which it appears.
+    #[allow(clippy::type_complexity)]
+    pub fn build_with_index(
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        leaves: &[LH::Leaf],
+    ) -> Result<(Self, HashMap<PH::Hash, Vec<u64>>)> {
+        // Construct the Merkle tree.
+        let merkle_tree = Self::new(leaf_hasher, path_hasher, leaves)?;
+
+        // Map each leaf hash to the indices at which it appears.
+        let mut positions = HashMap::<PH::Hash, Vec<u64>>::new();
+        for (leaf_index, leaf_hash) in merkle_tree.leaf_hashes()?.iter().enumerate() {
+            positions.entry(*leaf_hash).or_default().push(u64::try_from(leaf_index)?);
+        }
+
+        Ok((merkle_tree, positions))
+    }
+
+    /// Returns the root of the Merkle tree over the window of leaves `leaves[start..start + window]`,
+    /// without constructing the full Merkle tree. If the window extends past the last leaf, it is truncated.
+    ///
+    /// The root is identical to that of a new Merkle tree over the leaves in the window.
+    pub fn window_root(
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        leaves: &[LH::Leaf],
+        start: usize,
+        window: usize,
+    ) -> Result<PH::Hash> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+        // Ensure the window starts within the leaves.
+        ensure!(start <= leaves.len(), "The Merkle window start {start} is out of bounds");
+
+        // Compute the leaf hashes of the window.
+        let end = start.saturating_add(window).min(leaves.len());
+        let mut hashes = leaf_hasher.hash_leaves(&leaves[start..end])?;
+
+        // Compute the empty hash.
+        let empty_hash = path_hasher.hash_empty()?;
+        // Initialize the empty node hash for the current level.
+        let mut empty_node_hash = empty_hash;
+
+        // Compute the hashes for each level, iterating from the leaf level to the root level.
+        let mut tree_depth = 0u8;
+        while hashes.len() > 1 {
+            // Ensure the tree depth is within the depth bound.
+            ensure!(tree_depth < DEPTH, "Merkle tree cannot exceed depth {DEPTH}");
+            // Pad the current level with the empty node hash, if the number of nodes is odd.
+            if hashes.len() % 2 == 1 {
+                hashes.push(empty_node_hash);
+            }
+            // Compute the hashes for each node in the next level.
+            let tuples = hashes.chunks(2).map(|pair| (pair[0], pair[1])).collect::<Vec<_>>();
+            hashes = path_hasher.hash_all_children(&tuples)?;
+            // Update the empty node hash for the next level.
+            empty_node_hash = path_hasher.hash_children(&empty_node_hash, &empty_node_hash)?;
+            tree_depth += 1;
+        }
+
+        // Compute the root hash, by iterating from the root level up to `DEPTH`.
+        let mut root_hash = hashes.first().copied().unwrap_or(empty_hash);
+        for _ in tree_depth..DEPTH {
+            // Update the root hash, by hashing the current root hash with the empty hash.
+            root_hash = path_hasher.hash_children(&root_hash, &empty_hash)?;
+        }
+        Ok(root_hash)
+    }
+
     #[inline]
     /// Returns a new Merkle tree with the given new leaves appended to it.
     pub fn prepare_append(&self, new_leaves: &[LH::Leaf]) -> Result<Self> {
@@ -572,12 +671,50 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         };
 
         // Compute the absolute index of the leaf in the Merkle tree.
-        let mut index = start + leaf_index;
+        let index = start + leaf_index;
         // Ensure the leaf index is valid.
         ensure!(index < self.tree.len(), "The given Merkle leaf index is out of bounds");
         // Ensure the leaf hash matches the one in the tree.
         ensure!(self.tree[index] == leaf_hash, "The given Merkle leaf does not match the one in the Merkle tree");
 
+        // Compute the Merkle path.
+        self.compute_path(leaf_index)
+    }
+
+    #[inline]
+    /// Returns the Merkle path for the given leaf index, read from the stored internal hashes.
+    pub fn prove_by_index(&self, leaf_index: u64) -> Result<MerklePath<E, DEPTH>> {
+        // Ensure the leaf index is valid.
+        ensure!(leaf_index < self.number_of_leaves as u64, "The given Merkle leaf index is out of bounds");
+        // Compute the Merkle path.
+        self.compute_path(usize::try_from(leaf_index)?)
+    }
+
+    /// Returns the Merkle multi-proof for the given leaf indices, which stores each shared sibling once.
+    /// The leaf indices may be given in any order, and duplicate leaf indices are proven once.
+    pub fn prove_many(&self, indices: &[u64]) -> Result<MerkleMultiProof<E, DEPTH>> {
+        // Compute the Merkle path for each leaf index.
+        let paths = indices.iter().map(|index| self.prove_by_index(*index)).collect::<Result<Vec<_>>>()?;
+        // Construct the multi-proof from the Merkle paths.
+        MerkleMultiProof::from_paths(&paths)
+    }
+
+    /// Returns the Merkle path for the given leaf index, without checking the leaf.
+    fn compute_path(&self, leaf_index: usize) -> Result<MerklePath<E, DEPTH>> {
+        // Ensure the leaf index is valid.
+        ensure!(leaf_index < self.number_of_leaves, "The given Merkle leaf index is out of bounds");
+
+        // Compute the start index (on the left) for the leaf hashes level in the Merkle tree.
+        let start = match self.number_of_leaves.checked_next_power_of_two() {
+            Some(num_leaves) => num_leaves - 1,
+            None => bail!("Integer overflow when computing the Merkle tree start index"),
+        };
+
+        // Compute the absolute index of the leaf in the Merkle tree.
+        let mut index = start + leaf_index;
+        // Ensure the leaf index is valid.
+        ensure!(index < self.tree.len(), "The given Merkle leaf index is out of bounds");
+
         // Initialize a vector for the Merkle path.
         let mut path = Vec::with_capacity(DEPTH as usize);
 
@@ -604,11 +741,163 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         MerklePath::try_from((U64::new(leaf_index as u64), path))
     }
 
+    /// Returns the leaf index and Merkle path for each of the given leaves that satisfies the predicate.
+    ///
+    /// As the Merkle tree does not retain its leaves, the original leaves must be provided, in order.
+    /// Each Merkle path is read from the stored internal hashes, so shared siblings are not recomputed.
+    pub fn prove_where(
+        &self,
+        leaves: &[LH::Leaf],
+        predicate: impl Fn(&LH::Leaf) -> bool,
+    ) -> Result<Vec<(u64, MerklePath<E, DEPTH>)>> {
+        // Ensure the number of leaves matches the Merkle tree.
+        ensure!(
+            leaves.len() == self.number_of_leaves,
+            "Expected {} Merkle leaves, found {}",
+            self.number_of_leaves,
+            leaves.len()
+        );
+        // Prove each leaf that satisfies the predicate.
+        leaves
+            .iter()
+            .enumerate()
+            .filter(|(_, leaf)| predicate(leaf))
+            .map(|(leaf_index, leaf)| Ok((leaf_index as u64, self.prove(leaf_index, leaf)?)))
+            .collect()
+    }
+
+    /// Returns an archive of the Merkle paths for the given leaf indices.
+    ///
+    /// The leaf indices are sorted and deduplicated, and stored as LEB128-encoded deltas.
+    /// On each level, a sibling shared by several paths is stored once. Use `import_proof_archive`
+    /// to reconstruct the Merkle paths, in ascending order of leaf index.
+    pub fn export_proof_archive(&self, indices: &[u64]) -> Result<Vec<u8>> {
+        // Sort and deduplicate the leaf indices.
+        let indices = indices.iter().copied().collect::<BTreeSet<_>>();
+        // Compute the Merkle path for each leaf index.
+        let paths =
+            indices.iter().map(|index| self.compute_path(usize::try_from(*index)?)).collect::<Result<Vec<_>>>()?;
+
+        let mut archive = Vec::new();
+        // Write the number of leaf indices, and the delta of each leaf index from the previous one.
+        write_leb128(&mut archive, indices.len() as u64);
+        indices.iter().fold(0, |previous, index| {
+            write_leb128(&mut archive, index - previous);
+            *index
+        });
+        // Write the distinct siblings on each level, in ascending order of position.
+        for level in 0..DEPTH as usize {
+            let siblings = paths
+                .iter()
+                .map(|path| ((*path.leaf_index() >> level) ^ 1, path.siblings()[level]))
+                .collect::<BTreeMap<_, _>>();
+            siblings.values().try_for_each(|sibling| sibling.write_le(&mut archive))?;
+        }
+        Ok(archive)
+    }
+
+    /// Returns the Merkle paths from the given archive, in ascending order of leaf index.
+    /// See `export_proof_archive` for the archive format.
+    pub fn import_proof_archive(archive: &[u8]) -> Result<Vec<MerklePath<E, DEPTH>>> {
+        let mut reader = archive;
+        // Read the number of leaf indices, and the leaf indices from their deltas.
+        let num_indices = read_leb128(&mut reader)?;
+        let mut indices = Vec::new();
+        for i in 0..num_indices {
+            let delta = read_leb128(&mut reader)?;
+            let index = match indices.last() {
+                Some(previous) => u64::checked_add(*previous, delta),
+                None => Some(delta),
+            };
+            // Ensure the leaf indices are strictly increasing.
+            match index {
+                Some(index) if i == 0 || delta > 0 => indices.push(index),
+                _ => bail!("Found an invalid leaf index in the Merkle proof archive"),
+            }
+        }
+
+        // Read the distinct siblings on each level, and assign them to each path.
+        let mut siblings = vec![Vec::with_capacity(DEPTH as usize); indices.len()];
+        for level in 0..DEPTH as usize {
+            let positions = indices.iter().map(|index| (index >> level) ^ 1).collect::<BTreeSet<_>>();
+            let level_siblings = positions
+                .into_iter()
+                .map(|position| Ok((position, Field::read_le(&mut reader)?)))
+                .collect::<Result<BTreeMap<_, _>>>()?;
+            for (path_siblings, index) in siblings.iter_mut().zip_eq(&indices) {
+                path_siblings.push(level_siblings[&((index >> level) ^ 1)]);
+            }
+        }
+        // Ensure the entire archive was read.
+        ensure!(reader.is_empty(), "Found trailing bytes in the Merkle proof archive");
+
+        // Construct the Merkle paths.
+        indices
+            .into_iter()
+            .zip_eq(siblings)
+            .map(|(index, siblings)| MerklePath::try_from((U64::new(index), siblings)))
+            .collect()
+    }
+
+    /// Returns the Merkle path for the given leaf index and leaf, for verifiers that walk from the root to the leaf.
+    ///
+    /// The siblings are ordered from the root level down to the leaf level, so the `i`-th sibling is paired
+    /// with bit `DEPTH - 1 - i` of the leaf index. This path must be checked with `MerklePath::verify_top_down`.
+    pub fn prove_top_down(&self, leaf_index: usize, leaf: &LH::Leaf) -> Result<MerklePath<E, DEPTH>> {
+        // Compute the standard Merkle path.
+        let path = self.prove(leaf_index, leaf)?;
+        // Reverse the siblings, to order them from the root level down to the leaf level.
+        MerklePath::try_from((path.leaf_index(), path.siblings().iter().rev().copied().collect()))
+    }
+
     /// Returns `true` if the given Merkle path is valid for the given root and leaf.
     pub fn verify(&self, path: &MerklePath<E, DEPTH>, root: &PH::Hash, leaf: &LH::Leaf) -> bool {
         path.verify(&self.leaf_hasher, &self.path_hasher, root, leaf)
     }
 
+    /// Returns `true` if the given Merkle path is valid for the current root and the given leaf,
+    /// in which case the given counter is also incremented.
+    pub fn verify_and_count(&self, path: &MerklePath<E, DEPTH>, leaf: &LH::Leaf, counter: &AtomicU64) -> bool {
+        let is_valid = self.verify(path, &self.root, leaf);
+        if is_valid {
+            counter.fetch_add(1, Ordering::Relaxed);
+        }
+        is_valid
+    }
+
+    /// Returns whether each of the given Merkle paths is valid for the current root and its leaf.
+    pub fn reverify_proofs(&self, proofs: &[(MerklePath<E, DEPTH>, LH::Leaf)]) -> Vec<bool> {
+        proofs.iter().map(|(path, leaf)| self.verify(path, &self.root, leaf)).collect()
+    }
+
+    /// Returns a commitment to the ordered list of leaf hashes, which is independent of the tree shape.
+    ///
+    /// Each leaf hash is bound to its position, so the commitment changes if the leaves are reordered.
+    pub fn leaf_ordering_commitment<H: Hash<Input = Field<E>, Output = Field<E>>>(
+        &self,
+        hasher: &H,
+    ) -> Result<Field<E>> {
+        // Retrieve the leaf hashes.
+        let leaf_hashes = self.leaf_hashes()?;
+        // Construct the input, starting with the number of leaves.
+        let mut input = Vec::with_capacity(1 + 2 * leaf_hashes.len());
+        input.push(Field::from_u64(self.number_of_leaves as u64));
+        // Append each leaf hash, preceded by its position.
+        for (index, leaf_hash) in leaf_hashes.iter().enumerate() {
+            input.push(Field::from_u64(index as u64));
+            input.push(*leaf_hash);
+        }
+        // Hash the input.
+        hasher.hash(&input)
+    }
+
+    /// Returns the estimated heap memory usage of the Merkle tree, in bytes.
+    ///
+    /// The leaf hashes are stored alongside the internal nodes, so this is the allocated size of the tree.
+    pub fn memory_footprint(&self) -> usize {
+        self.tree.capacity() * std::mem::size_of::<PH::Hash>()
+    }
+
     /// Returns the Merkle root of the tree.
     pub const fn root(&self) -> &PH::Hash {
         &self.root
@@ -781,6 +1070,35 @@ fn tree_depth<const DEPTH: u8>(tree_size: usize) -> Result<u8> {
     }
 }
 
+/// Writes the given value to the buffer as an unsigned LEB128 integer.
+fn write_leb128(buffer: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buffer.push((value.to_le_bytes()[0] & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    buffer.push(value.to_le_bytes()[0]);
+}
+
+/// Reads an unsigned LEB128 integer from the reader.
+fn read_leb128(reader: &mut &[u8]) -> Result<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        // Read the next byte.
+        let (byte, remainder) = match reader.split_first() {
+            Some((byte, remainder)) => (*byte, remainder),
+            None => bail!("Unexpected end of LEB128 integer"),
+        };
+        *reader = remainder;
+        // Ensure the value does not overflow.
+        ensure!(shift < 63 || byte <= 1, "Found an LEB128 integer that exceeds 64 bits");
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    bail!("Found an LEB128 integer that exceeds 64 bits")
+}
+
 /// Returns the index of the left child, given an index.
 #[inline]
 const fn left_child(index: usize) -> usize {
@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A Merkle path that omits each sibling equal to the empty hash of its level.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CompressedMerklePath<E: Environment, const DEPTH: u8> {
+    /// The leaf index for the path.
+    leaf_index: U64<E>,
+    /// The mask of omitted siblings, where bit `i` is set if the sibling on level `i` was omitted.
+    mask: u64,
+    /// The `siblings` contains a list of the non-omitted sibling hashes from the leaf to the root.
+    siblings: Vec<Field<E>>,
+}
+
+impl<E: Environment, const DEPTH: u8> TryFrom<(U64<E>, u64, Vec<Field<E>>)> for CompressedMerklePath<E, DEPTH> {
+    type Error = Error;
+
+    /// Returns a new instance of a compressed Merkle path.
+    fn try_from((leaf_index, mask, siblings): (U64<E>, u64, Vec<Field<E>>)) -> Result<Self> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+        // Ensure the leaf index is within the tree depth.
+        ensure!((*leaf_index as u128) < (1u128 << DEPTH), "Found an out of bounds Merkle leaf index");
+        // Ensure the mask is within the tree depth.
+        ensure!((mask as u128) < (1u128 << DEPTH), "Found an out of bounds compressed Merkle path mask");
+        // Ensure the number of siblings matches the mask.
+        let num_siblings = DEPTH as usize - mask.count_ones() as usize;
+        ensure!(siblings.len() == num_siblings, "Found an incorrect compressed Merkle path length");
+        // Return the compressed Merkle path.
+        Ok(Self { leaf_index, mask, siblings })
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> CompressedMerklePath<E, DEPTH> {
+    /// Returns the leaf index for the path.
+    pub fn leaf_index(&self) -> U64<E> {
+        self.leaf_index
+    }
+
+    /// Returns the mask of omitted siblings, where bit `i` is set if the sibling on level `i` was omitted.
+    pub fn mask(&self) -> u64 {
+        self.mask
+    }
+
+    /// Returns the non-omitted siblings for the path.
+    pub fn siblings(&self) -> &[Field<E>] {
+        &self.siblings
+    }
+
+    /// Returns the Merkle path, by restoring each omitted sibling from the given empty hash of its level.
+    pub fn decompress(&self, empty_hashes: &[Field<E>]) -> Result<MerklePath<E, DEPTH>> {
+        // Ensure there is an empty hash for each level.
+        ensure!(empty_hashes.len() == DEPTH as usize, "Expected {DEPTH} empty hashes, found {}", empty_hashes.len());
+        // Restore the siblings on each level.
+        let mut siblings = self.siblings.iter();
+        let siblings = empty_hashes
+            .iter()
+            .enumerate()
+            .map(|(level, empty_hash)| match (self.mask >> level) & 1 == 1 {
+                true => Ok(*empty_hash),
+                false => siblings.next().copied().ok_or_else(|| anyhow!("Missing a compressed Merkle path sibling")),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Return the Merkle path.
+        MerklePath::try_from((self.leaf_index, siblings))
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
+    /// Returns the compressed Merkle path, which omits each sibling equal to the given empty hash of its level.
+    pub fn compress(&self, empty_hashes: &[Field<E>]) -> Result<CompressedMerklePath<E, DEPTH>> {
+        // Ensure there is an empty hash for each level.
+        ensure!(empty_hashes.len() == DEPTH as usize, "Expected {DEPTH} empty hashes, found {}", empty_hashes.len());
+        // Compute the mask of omitted siblings, and the non-omitted siblings.
+        let mut mask = 0u64;
+        let mut siblings = Vec::new();
+        for (level, (sibling, empty_hash)) in self.siblings().iter().zip_eq(empty_hashes).enumerate() {
+            match sibling == empty_hash {
+                true => mask |= 1 << level,
+                false => siblings.push(*sibling),
+            }
+        }
+        // Return the compressed Merkle path.
+        CompressedMerklePath::try_from((self.leaf_index(), mask, siblings))
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> FromBytes for CompressedMerklePath<E, DEPTH> {
+    /// Reads in a compressed Merkle path from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the leaf index.
+        let leaf_index = u64::read_le(&mut reader)?;
+        // Read the mask.
+        let mask = u64::read_le(&mut reader)?;
+        // Ensure the mask is within the tree depth.
+        if (mask as u128) >= (1u128 << DEPTH) {
+            return Err(error("Found an out of bounds compressed Merkle path mask"));
+        }
+        // Read the non-omitted siblings.
+        let num_siblings = DEPTH as usize - mask.count_ones() as usize;
+        let siblings = (0..num_siblings)
+            .map(|_| Ok(Field::new(FromBytes::read_le(&mut reader)?)))
+            .collect::<IoResult<Vec<_>>>()?;
+        // Return the compressed Merkle path.
+        Self::try_from((U64::new(leaf_index), mask, siblings)).map_err(error)
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> ToBytes for CompressedMerklePath<E, DEPTH> {
+    /// Writes the compressed Merkle path to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the leaf index.
+        self.leaf_index.write_le(&mut writer)?;
+        // Write the mask.
+        self.mask.write_le(&mut writer)?;
+        // Write the non-omitted siblings.
+        self.siblings.iter().try_for_each(|sibling| sibling.write_le(&mut writer))
+    }
+}
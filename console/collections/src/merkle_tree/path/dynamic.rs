@@ -0,0 +1,149 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A Merkle path whose depth is chosen at runtime, rather than fixed by a const generic.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MerklePathDyn<E: Environment> {
+    /// The depth of the Merkle tree.
+    depth: u8,
+    /// The leaf index for the path.
+    leaf_index: U64<E>,
+    /// The `siblings` contains a list of sibling hashes from the leaf to the root.
+    siblings: Vec<Field<E>>,
+}
+
+impl<E: Environment> TryFrom<(u8, U64<E>, Vec<Field<E>>)> for MerklePathDyn<E> {
+    type Error = Error;
+
+    /// Returns a new instance of a Merkle path with the given depth.
+    fn try_from((depth, leaf_index, siblings): (u8, U64<E>, Vec<Field<E>>)) -> Result<Self> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(depth > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(depth <= 64u8, "Merkle tree depth must be less than or equal to 64");
+        // Ensure the leaf index is within the tree depth.
+        ensure!((*leaf_index as u128) < (1u128 << depth), "Found an out of bounds Merkle leaf index");
+        // Ensure the Merkle path is the correct length.
+        ensure!(siblings.len() == depth as usize, "Found an incorrect Merkle path length");
+        // Return the Merkle path.
+        Ok(Self { depth, leaf_index, siblings })
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> TryFrom<MerklePath<E, DEPTH>> for MerklePathDyn<E> {
+    type Error = Error;
+
+    /// Returns the runtime-depth equivalent of the given Merkle path.
+    fn try_from(path: MerklePath<E, DEPTH>) -> Result<Self> {
+        Self::try_from((DEPTH, path.leaf_index(), path.siblings().to_vec()))
+    }
+}
+
+impl<E: Environment> MerklePathDyn<E> {
+    /// Returns the depth of the Merkle tree.
+    pub const fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Returns the leaf index for the path.
+    pub fn leaf_index(&self) -> U64<E> {
+        self.leaf_index
+    }
+
+    /// Returns the siblings for the path.
+    pub fn siblings(&self) -> &[Field<E>] {
+        &self.siblings
+    }
+
+    /// Returns the Merkle path with the const-generic depth `DEPTH`, if it matches the depth of this path.
+    pub fn to_const<const DEPTH: u8>(&self) -> Result<MerklePath<E, DEPTH>> {
+        // Ensure the depth matches.
+        ensure!(self.depth == DEPTH, "Expected a Merkle path of depth {DEPTH}, found depth {}", self.depth);
+        // Return the Merkle path.
+        MerklePath::try_from((self.leaf_index, self.siblings.clone()))
+    }
+
+    /// Returns `true` if the Merkle path is valid for the given root and leaf.
+    pub fn verify<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaf: &LH::Leaf,
+    ) -> bool {
+        self.verify_with_reason(leaf_hasher, path_hasher, root, leaf).is_ok()
+    }
+
+    /// Returns `Ok(())` if the Merkle path is valid for the given root and leaf.
+    /// Otherwise, returns the reason the Merkle path is invalid.
+    pub fn verify_with_reason<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaf: &LH::Leaf,
+    ) -> Result<(), MerklePathError> {
+        // Ensure the Merkle path is well-formed, before hashing the leaf.
+        prevalidate_path(self.depth, 1, *self.leaf_index, self.siblings.len())?;
+
+        // Compute the leaf hash.
+        let leaf_hash = leaf_hasher.hash_leaf(leaf).map_err(MerklePathError::LeafHashFailed)?;
+        // Compute the ordering of the current hash and sibling hash on each level.
+        let levels = self
+            .siblings
+            .iter()
+            .enumerate()
+            .map(|(i, sibling)| (((*self.leaf_index >> i) & 1) == 0, std::array::from_ref(sibling)));
+        // Compute the root of the Merkle path, and ensure it matches the given root.
+        match is_equal_constant_time_wide(&fold_levels(path_hasher, [leaf_hash], levels)?, &[*root]) {
+            true => Ok(()),
+            false => Err(MerklePathError::RootMismatch),
+        }
+    }
+}
+
+impl<E: Environment> FromBytes for MerklePathDyn<E> {
+    /// Reads in a Merkle path from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the depth.
+        let depth = u8::read_le(&mut reader)?;
+        // Ensure the depth is valid, before reading the siblings.
+        if depth == 0 || depth > 64 {
+            return Err(error(format!("Invalid Merkle tree depth {depth}")));
+        }
+        // Read the leaf index.
+        let leaf_index = u64::read_le(&mut reader)?;
+        // Read the Merkle path siblings.
+        let siblings =
+            (0..depth).map(|_| Ok(Field::new(FromBytes::read_le(&mut reader)?))).collect::<IoResult<Vec<_>>>()?;
+        // Return the Merkle path.
+        Self::try_from((depth, U64::new(leaf_index), siblings)).map_err(error)
+    }
+}
+
+impl<E: Environment> ToBytes for MerklePathDyn<E> {
+    /// Writes the Merkle path to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the depth.
+        self.depth.write_le(&mut writer)?;
+        // Write the leaf index.
+        self.leaf_index.write_le(&mut writer)?;
+        // Write the Merkle path siblings.
+        self.siblings.iter().try_for_each(|sibling| sibling.write_le(&mut writer))
+    }
+}
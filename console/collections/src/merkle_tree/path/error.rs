@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The reason a Merkle path failed to verify.
+#[derive(Debug)]
+pub enum MerklePathError {
+    /// The leaf index is not within the tree depth.
+    LeafIndexOutOfBounds,
+    /// The number of siblings does not match the tree depth.
+    IncorrectPathLength { expected: usize, found: usize },
+    /// The leaf hasher failed to hash the leaf.
+    LeafHashFailed(Error),
+    /// The path hasher failed to hash a pair of child nodes.
+    PathHashFailed(Error),
+    /// The computed root does not match the given root.
+    RootMismatch,
+}
+
+impl Display for MerklePathError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::LeafIndexOutOfBounds => write!(f, "Found an out of bounds Merkle leaf index"),
+            Self::IncorrectPathLength { expected, found } => {
+                write!(f, "Found an incorrect Merkle path length: expected {expected}, found {found}")
+            }
+            Self::LeafHashFailed(error) => write!(f, "Failed to hash the Merkle leaf during verification: {error}"),
+            Self::PathHashFailed(error) => write!(f, "Failed to hash the Merkle path during verification: {error}"),
+            Self::RootMismatch => write!(f, "Merkle path root mismatch"),
+        }
+    }
+}
+
+impl std::error::Error for MerklePathError {}
@@ -0,0 +1,56 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const DEPTH: u8> FromBits for MerklePath<E, DEPTH> {
+    /// Initializes a new Merkle path from a list of **little-endian** bits,
+    /// i.e. the leaf index as 64 bits, followed by the bits of each sibling in leaf-to-root order.
+    fn from_bits_le(bits_le: &[bool]) -> Result<Self> {
+        Self::from_bits(bits_le, U64::from_bits_le, Field::from_bits_le)
+    }
+
+    /// Initializes a new Merkle path from a list of **big-endian** bits,
+    /// i.e. the leaf index as 64 bits, followed by the bits of each sibling in leaf-to-root order.
+    fn from_bits_be(bits_be: &[bool]) -> Result<Self> {
+        Self::from_bits(bits_be, U64::from_bits_be, Field::from_bits_be)
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
+    /// Initializes a new Merkle path from a list of bits, using the given decoders for the leaf index and the siblings.
+    fn from_bits(
+        bits: &[bool],
+        leaf_index_from_bits: impl Fn(&[bool]) -> Result<U64<E>>,
+        sibling_from_bits: impl Fn(&[bool]) -> Result<Field<E>>,
+    ) -> Result<Self> {
+        // Ensure the number of bits is correct.
+        let leaf_index_size = U64::<E>::size_in_bits();
+        let sibling_size = Field::<E>::size_in_bits();
+        let expected_size = leaf_index_size + DEPTH as usize * sibling_size;
+        ensure!(
+            bits.len() == expected_size,
+            "Expected {expected_size} bits for a Merkle path of depth {DEPTH}, found {}",
+            bits.len()
+        );
+
+        // Read the leaf index.
+        let (leaf_index_bits, sibling_bits) = bits.split_at(leaf_index_size);
+        let leaf_index = leaf_index_from_bits(leaf_index_bits)?;
+        // Read the siblings.
+        let siblings = sibling_bits.chunks(sibling_size).map(&sibling_from_bits).collect::<Result<Vec<_>>>()?;
+        // Return the Merkle path.
+        Self::try_from((leaf_index, siblings))
+    }
+}
@@ -12,46 +12,289 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod compressed;
+pub use compressed::*;
+
+mod dynamic;
+pub use dynamic::*;
+
+mod error;
+pub use error::*;
+
+mod from_bits;
+
+mod multi_proof;
+pub use multi_proof::*;
+
+mod non_membership;
+pub use non_membership::*;
+
+mod parse;
+mod to_bits;
+
 use super::*;
 
+use subtle::ConstantTimeEq;
+
+/// A Merkle path, whose node hashes are `WIDTH` field elements wide.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub struct MerklePath<E: Environment, const DEPTH: u8> {
+pub struct MerklePath<E: Environment, const DEPTH: u8, const WIDTH: usize = 1> {
     /// The leaf index for the path.
     leaf_index: U64<E>,
-    /// The `siblings` contains a list of sibling hashes from the leaf to the root.
+    /// The `siblings` contains a list of sibling hashes from the leaf to the root,
+    /// as `WIDTH` consecutive field elements for each sibling.
     siblings: Vec<Field<E>>,
 }
 
-impl<E: Environment, const DEPTH: u8> TryFrom<(U64<E>, Vec<Field<E>>)> for MerklePath<E, DEPTH> {
+impl<E: Environment, const DEPTH: u8, const WIDTH: usize> TryFrom<(U64<E>, Vec<Field<E>>)>
+    for MerklePath<E, DEPTH, WIDTH>
+{
     type Error = Error;
 
-    /// Returns a new instance of a Merkle path.
+    /// Returns a new instance of a Merkle path, from `WIDTH` consecutive field elements for each sibling.
     fn try_from((leaf_index, siblings): (U64<E>, Vec<Field<E>>)) -> Result<Self> {
         // Ensure the Merkle tree depth is greater than 0.
         ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
         // Ensure the Merkle tree depth is less than or equal to 64.
         ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+        // Ensure the node width is greater than 0.
+        ensure!(WIDTH > 0, "Merkle node width must be greater than 0");
         // Ensure the leaf index is within the tree depth.
         ensure!((*leaf_index as u128) < (1u128 << DEPTH), "Found an out of bounds Merkle leaf index");
         // Ensure the Merkle path is the correct length.
-        ensure!(siblings.len() == DEPTH as usize, "Found an incorrect Merkle path length");
+        ensure!(siblings.len() == DEPTH as usize * WIDTH, "Found an incorrect Merkle path length");
         // Return the Merkle path.
         Ok(Self { leaf_index, siblings })
     }
 }
 
-impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
+impl<E: Environment, const DEPTH: u8, const WIDTH: usize> MerklePath<E, DEPTH, WIDTH> {
     /// Returns the leaf index for the path.
     pub fn leaf_index(&self) -> U64<E> {
         self.leaf_index
     }
 
-    /// Returns the siblings for the path.
+    /// Returns the siblings for the path, as `WIDTH` consecutive field elements for each sibling.
     pub fn siblings(&self) -> &[Field<E>] {
         &self.siblings
     }
 
+    /// Returns the size of the serialized Merkle path in bytes, i.e. the leaf index (`u64`) and `DEPTH` siblings.
+    pub const fn size_in_bytes() -> usize {
+        8 + DEPTH as usize * WIDTH * Field::<E>::SIZE_IN_BYTES
+    }
+
+    /// Returns `Ok(())` if the leaf index is within the tree depth, and the path length matches the depth.
+    ///
+    /// These structural checks are cheap, and are performed by every verifier before any hashing,
+    /// so callers may use them to reject malformed paths before hashing a batch.
+    pub fn prevalidate(&self) -> Result<(), MerklePathError> {
+        prevalidate_path(DEPTH, WIDTH, *self.leaf_index, self.siblings.len())
+    }
+
+    /// Returns `true` if the Merkle path is valid for the given root and precomputed leaf hash,
+    /// where each node hash is `WIDTH` field elements wide.
+    pub fn verify_wide<PH: PathHash<Hash = Field<E>>>(
+        &self,
+        path_hasher: &PH,
+        root: &[Field<E>; WIDTH],
+        leaf_hash: &[Field<E>; WIDTH],
+    ) -> bool {
+        match self.compute_root_wide(path_hasher, *leaf_hash) {
+            Ok(candidate_root) => is_equal_constant_time_wide(&candidate_root, root),
+            Err(_) => false,
+        }
+    }
+
+    /// Returns the root computed by folding the given leaf hash with the siblings of the Merkle path,
+    /// where each node hash is `WIDTH` field elements wide.
+    pub fn compute_root_wide<PH: PathHash<Hash = Field<E>>>(
+        &self,
+        path_hasher: &PH,
+        leaf_hash: [Field<E>; WIDTH],
+    ) -> Result<[Field<E>; WIDTH], MerklePathError> {
+        // Ensure the Merkle path is well-formed.
+        self.prevalidate()?;
+        // Pair each indicator bit with the sibling hash on that level.
+        let siblings = self.siblings.chunks_exact(WIDTH).filter_map(|sibling| sibling.try_into().ok());
+        let levels = (0..DEPTH).map(|i| ((*self.leaf_index >> i) & 1) == 0).zip_eq(siblings);
+        // Fold the leaf hash with the sibling hash on each level.
+        fold_levels(path_hasher, leaf_hash, levels)
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
+    /// Returns an iterator over each level of the path, from the leaf level to the root level.
+    ///
+    /// Each item is the indicator bit, which is `true` if the current hash is the left child,
+    /// paired with the sibling hash on that level.
+    pub fn iter_levels(&self) -> impl Iterator<Item = (bool, &Field<E>)> {
+        (0..DEPTH).map(|i| ((*self.leaf_index >> i) & 1) == 0).zip_eq(&self.siblings)
+    }
+
+    /// Returns the `DEPTH` indicator bits of the path, from the leaf level to the root level,
+    /// where each bit is `true` if the current hash is the left child on that level.
+    pub fn path_directions(&self) -> Vec<bool> {
+        self.iter_levels().map(|(indicator, _)| indicator).collect()
+    }
+
+    /// Returns the `DEPTH` least significant bits of the leaf index, in little-endian order.
+    ///
+    /// Bit `i` selects the order of the children on level `i` during verification, i.e. it is `true`
+    /// if the current hash is the right child on that level, and is the negation of `path_directions`.
+    pub fn leaf_index_bits(&self) -> Vec<bool> {
+        (0..DEPTH).map(|i| ((*self.leaf_index >> i) & 1) == 1).collect()
+    }
+
+    /// Reads in `count` consecutive Merkle paths from a buffer.
+    ///
+    /// As each path is `size_in_bytes` long, exactly `count * size_in_bytes` bytes are consumed from the reader.
+    pub fn read_le_many<R: Read>(mut reader: R, count: usize) -> IoResult<Vec<Self>> {
+        (0..count).map(|_| Self::read_le(&mut reader)).collect()
+    }
+
+    /// Writes the given Merkle paths consecutively to a buffer, without a length prefix.
+    pub fn write_le_many<W: Write>(paths: &[Self], mut writer: W) -> IoResult<()> {
+        paths.iter().try_for_each(|path| path.write_le(&mut writer))
+    }
+
+    /// Returns the number of hashes computed to verify the path, i.e. one leaf hash and `DEPTH` path hashes.
+    pub const fn verification_hash_count(&self) -> usize {
+        DEPTH as usize + 1
+    }
+
+    /// Returns `true` if this path and `other` agree from `from_level` upward, i.e. both leaves lie in
+    /// the same subtree of height `from_level`, and both paths have identical siblings from that level to the root.
+    ///
+    /// This check only compares the stored fields, and does not verify either path.
+    pub fn shares_subtree_with(&self, other: &Self, from_level: u8) -> bool {
+        // Clamp the level to the root level.
+        let from_level = from_level.min(DEPTH);
+        // Ensure the leaf indices agree above the level.
+        let subtree_index = |leaf_index: u64| leaf_index.checked_shr(u32::from(from_level)).unwrap_or(0);
+        if subtree_index(*self.leaf_index) != subtree_index(*other.leaf_index) {
+            return false;
+        }
+        // Ensure the siblings agree from the level upward.
+        self.siblings.get(from_level as usize..) == other.siblings.get(from_level as usize..)
+    }
+
+    /// Returns the Merkle path for the same leaf, in a tree whose leaves were cyclically shifted by `offset`.
+    ///
+    /// The new leaf index is `(leaf_index + offset) mod 2^DEPTH`. If `offset` is a multiple of `2^k`,
+    /// the aligned subtree of height `k` containing the leaf is moved intact, so the lowest `k` siblings
+    /// are preserved. The remaining `DEPTH - k` siblings must be supplied in `upper_siblings`,
+    /// from the shifted tree, in leaf-to-root order. Otherwise, the rotated path will not verify.
+    pub fn rotate(&self, offset: i64, upper_siblings: Vec<Field<E>>) -> Result<Self> {
+        // Compute the capacity of the Merkle tree.
+        let capacity = 1i128 << DEPTH;
+        // Compute the offset, modulo the capacity.
+        let offset = (offset as i128).rem_euclid(capacity);
+        // Compute the new leaf index.
+        let leaf_index = u64::try_from((*self.leaf_index as i128 + offset).rem_euclid(capacity))?;
+
+        // Compute the number of siblings preserved by the rotation.
+        let num_preserved = match offset {
+            0 => DEPTH as usize,
+            _ => offset.trailing_zeros() as usize,
+        };
+        // Ensure the number of upper siblings is correct.
+        let num_upper = DEPTH as usize - num_preserved;
+        ensure!(
+            upper_siblings.len() == num_upper,
+            "Rotating by {offset} requires {num_upper} upper siblings, found {}",
+            upper_siblings.len()
+        );
+
+        // Construct the siblings for the rotated path.
+        let mut siblings = self.siblings[..num_preserved].to_vec();
+        siblings.extend(upper_siblings);
+        // Return the rotated Merkle path.
+        Self::try_from((U64::new(leaf_index), siblings))
+    }
+
+    /// Returns the Merkle path for the same leaf, in a Merkle tree of depth `NEW_DEPTH` that contains this tree
+    /// as its leftmost subtree, by appending the given higher-level siblings in leaf-to-root order.
+    ///
+    /// As the new levels are added at the top, the leaf index is unchanged.
+    pub fn extend_depth<const NEW_DEPTH: u8>(
+        mut self,
+        extra_siblings: Vec<Field<E>>,
+    ) -> Result<MerklePath<E, NEW_DEPTH>> {
+        // Ensure the new depth is not less than the current depth.
+        ensure!(NEW_DEPTH >= DEPTH, "Cannot extend a Merkle path of depth {DEPTH} to depth {NEW_DEPTH}");
+        // Ensure the number of extra siblings is correct.
+        let num_extra = (NEW_DEPTH - DEPTH) as usize;
+        ensure!(
+            extra_siblings.len() == num_extra,
+            "Extending to depth {NEW_DEPTH} requires {num_extra} extra siblings, found {}",
+            extra_siblings.len()
+        );
+        // Construct the siblings for the extended path.
+        let mut siblings = std::mem::take(&mut self.siblings);
+        siblings.extend(extra_siblings);
+        // Return the extended Merkle path.
+        MerklePath::try_from((self.leaf_index, siblings))
+    }
+
+    /// Updates this Merkle path in place, after the leaf at `changed_leaf_index` is updated to the
+    /// leaf with hash `new_leaf_hash`, so that it verifies against the new root.
+    ///
+    /// The `old_path` and `new_path` are the paths of the changed leaf before and after the update.
+    /// As a single leaf update does not change the siblings of that leaf, they must be equal.
+    /// This Merkle path must be from the same tree as `old_path`, so that both leaves share every
+    /// sibling above the level where their subtrees meet. Only the sibling at that level changes.
+    ///
+    /// Note that the paths alone cannot determine the new sibling, as a Merkle path holds only the
+    /// siblings of its leaf, and not the leaf itself. Hence, in addition to the paths, this takes
+    /// the hash of the new leaf, and the path hasher to recompute the subtree containing it.
+    pub fn rebase<PH: PathHash<Hash = Field<E>>>(
+        &mut self,
+        path_hasher: &PH,
+        changed_leaf_index: u64,
+        old_path: &Self,
+        new_path: &Self,
+        new_leaf_hash: Field<E>,
+    ) -> Result<()> {
+        // Ensure the paths are for the changed leaf.
+        ensure!(
+            *old_path.leaf_index == changed_leaf_index && *new_path.leaf_index == changed_leaf_index,
+            "The Merkle paths are not for the changed leaf index {changed_leaf_index}"
+        );
+        // Ensure the siblings of the changed leaf are unchanged.
+        ensure!(old_path.siblings == new_path.siblings, "The Merkle paths are not for a single leaf update");
+
+        // If the changed leaf is the leaf of this path, then none of the siblings change.
+        let difference = *self.leaf_index ^ changed_leaf_index;
+        if difference == 0 {
+            return Ok(());
+        }
+
+        // Compute the level where the subtrees of the two leaves meet.
+        let level = (u64::BITS - 1 - difference.leading_zeros()) as usize;
+        ensure!(level < DEPTH as usize, "Found an out of bounds Merkle leaf index");
+        // Ensure this path shares the siblings of the changed leaf above that level.
+        ensure!(
+            self.siblings[level + 1..] == old_path.siblings[level + 1..],
+            "The Merkle path is not from the same tree as the changed leaf"
+        );
+
+        // Compute the new root of the subtree of height `level` containing the changed leaf.
+        let subtree_root = new_path.siblings[..level].iter().enumerate().try_fold(
+            new_leaf_hash,
+            |current_hash, (i, sibling_hash)| match ((changed_leaf_index >> i) & 1) == 0 {
+                true => path_hasher.hash_children(&current_hash, sibling_hash),
+                false => path_hasher.hash_children(sibling_hash, &current_hash),
+            },
+        )?;
+        // Update the sibling at that level.
+        self.siblings[level] = subtree_root;
+        Ok(())
+    }
+
     /// Returns `true` if the Merkle path is valid for the given root and leaf.
+    ///
+    /// The computed root is compared to the given root with `subtle::ConstantTimeEq`, over their byte encodings.
     pub fn verify<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
         &self,
         leaf_hasher: &LH,
@@ -59,68 +302,354 @@ impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
         root: &PH::Hash,
         leaf: &LH::Leaf,
     ) -> bool {
-        // Ensure the leaf index is within the tree depth.
-        if (*self.leaf_index as u128) >= (1u128 << DEPTH) {
-            eprintln!("Found an out of bounds Merkle leaf index");
+        self.verify_with_reason(leaf_hasher, path_hasher, root, leaf).is_ok()
+    }
+
+    /// Returns `Ok(())` if the Merkle path is valid for the given root and leaf.
+    /// Otherwise, returns the reason the Merkle path is invalid.
+    pub fn verify_with_reason<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaf: &LH::Leaf,
+    ) -> Result<(), MerklePathError> {
+        // Ensure the Merkle path is well-formed, before hashing the leaf.
+        self.prevalidate()?;
+        // Compute the leaf hash.
+        let leaf_hash = leaf_hasher.hash_leaf(leaf).map_err(MerklePathError::LeafHashFailed)?;
+        // Compute the root of the Merkle path, and ensure it matches the given root.
+        match is_equal_constant_time(&self.compute_root_from_leaf_hash(path_hasher, leaf_hash)?, root) {
+            true => Ok(()),
+            false => Err(MerklePathError::RootMismatch),
+        }
+    }
+
+    /// Returns `true` if the Merkle path is valid for the given root and precomputed leaf hash.
+    ///
+    /// This skips hashing the leaf, so that leaf hashes may be computed in a batch by the caller.
+    pub fn verify_with_leaf_hash<PH: PathHash<Hash = Field<E>>>(
+        &self,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaf_hash: &PH::Hash,
+    ) -> bool {
+        match self.compute_root_from_leaf_hash(path_hasher, *leaf_hash) {
+            Ok(candidate_root) => is_equal_constant_time(&candidate_root, root),
+            Err(_) => false,
+        }
+    }
+
+    /// Returns the root of the Merkle path for the given leaf.
+    pub fn compute_root<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        leaf: &LH::Leaf,
+    ) -> Result<PH::Hash> {
+        // Compute the leaf hash.
+        let leaf_hash = leaf_hasher.hash_leaf(leaf).map_err(MerklePathError::LeafHashFailed)?;
+        // Compute the root from the leaf hash.
+        Ok(self.compute_root_from_leaf_hash(path_hasher, leaf_hash)?)
+    }
+
+    /// Returns `Ok(())` if the Merkle path is valid for the given root and leaf.
+    ///
+    /// Otherwise, returns an error that distinguishes a malformed path or a hashing failure
+    /// from a well-formed path that does not match the given root. See `MerklePathError`.
+    pub fn ensure_verifies<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaf: &LH::Leaf,
+    ) -> Result<()> {
+        Ok(self.verify_with_reason(leaf_hasher, path_hasher, root, leaf)?)
+    }
+
+    /// Returns `true` if the top-down Merkle path is valid for the given root and leaf.
+    ///
+    /// A top-down path orders its siblings from the root level down to the leaf level, so the `i`-th
+    /// sibling is paired with bit `DEPTH - 1 - i` of the leaf index. See `MerkleTree::prove_top_down`.
+    pub fn verify_top_down<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaf: &LH::Leaf,
+    ) -> bool {
+        // Construct the equivalent path, with the siblings ordered from the leaf level up to the root level.
+        let path = Self { leaf_index: self.leaf_index, siblings: self.siblings.iter().rev().copied().collect() };
+        // Compute the root of the Merkle path.
+        match path.compute_root(leaf_hasher, path_hasher, leaf) {
+            Ok(candidate_root) => is_equal_constant_time(&candidate_root, root),
+            Err(_) => false,
+        }
+    }
+
+    /// Returns `true` if every Merkle path is valid for the given root and its leaf.
+    ///
+    /// The leaf index and length of every path are checked before any hashing is performed,
+    /// and verification stops at the first invalid path. An empty batch is valid.
+    pub fn batch_verify<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        paths: &[(&Self, &LH::Leaf)],
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+    ) -> bool {
+        // Ensure each leaf index is within the tree depth, and each path length matches the expected depth.
+        if paths.iter().any(|(path, _)| path.prevalidate().is_err()) {
+            return false;
+        }
+
+        // Ensure each path computes the given root.
+        paths.iter().all(|(path, leaf)| match path.compute_root(leaf_hasher, path_hasher, leaf) {
+            Ok(candidate_root) => is_equal_constant_time(&candidate_root, root),
+            Err(_) => false,
+        })
+    }
+
+    /// Returns `true` if every Merkle path is valid for the given root and its leaf,
+    /// by verifying the paths in parallel. An empty batch is valid.
+    #[cfg(not(feature = "serial"))]
+    pub fn par_batch_verify<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        paths: &[(&Self, &LH::Leaf)],
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+    ) -> bool {
+        // Ensure each leaf index is within the tree depth, and each path length matches the expected depth.
+        if paths.iter().any(|(path, _)| path.prevalidate().is_err()) {
+            return false;
+        }
+        paths.par_iter().all(|(path, leaf)| path.verify(leaf_hasher, path_hasher, root, leaf))
+    }
+
+    /// Returns `true` if the Merkle path is valid for the given root and leaf, where the leaf is hashed
+    /// under the given domain, i.e. the leaf hash is computed over the bits of `domain` followed by `leaf`.
+    ///
+    /// This allows a verifier to check a leaf under a specific leaf hash domain, without rebuilding the tree.
+    pub fn verify_with_leaf_domain<LH: LeafHash<Hash = PH::Hash, Leaf = Vec<bool>>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        domain: &[u8],
+        leaf: &LH::Leaf,
+    ) -> bool {
+        // Construct the domain-separated leaf.
+        let mut domain_leaf = domain.to_bits_le();
+        domain_leaf.extend(leaf);
+        // Compute the leaf hash, and then the root of the Merkle path.
+        match leaf_hasher
+            .hash_leaf(&domain_leaf)
+            .and_then(|leaf_hash| Ok(self.compute_root_from_leaf_hash(path_hasher, leaf_hash)?))
+        {
+            Ok(candidate_root) => is_equal_constant_time(&candidate_root, root),
+            Err(_) => false,
+        }
+    }
+
+    /// Returns `true` if the Merkle path is valid for the given root and leaf, in a Merkle tree where
+    /// each parent hash is domain-separated by its level.
+    ///
+    /// On level `i`, counted from `0` at the leaf level as in `iter_levels`, the parent hash is
+    /// `hash_children(domain, hash_children(left, right))` if `domain(i)` is `Some(domain)`,
+    /// and `hash_children(left, right)` if it is `None`. Hence, a domain that is always `None` matches `verify`.
+    pub fn verify_with_domain<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaf: &LH::Leaf,
+        domain: &dyn Fn(u8) -> Option<Field<E>>,
+    ) -> bool {
+        // Ensure the Merkle path is well-formed.
+        if self.prevalidate().is_err() {
             return false;
         }
-        // Ensure the path length matches the expected depth.
-        else if self.siblings.len() != DEPTH as usize {
-            eprintln!("Found an incorrect Merkle path length");
+        // Compute the leaf hash.
+        let Ok(leaf_hash) = leaf_hasher.hash_leaf(leaf) else {
             return false;
+        };
+        // Fold the leaf hash with the sibling hash on each level, mixing in the domain of the level.
+        let candidate_root = (0..DEPTH).zip_eq(self.iter_levels()).try_fold(
+            leaf_hash,
+            |current_hash, (level, (indicator, sibling_hash))| {
+                let parent = match indicator {
+                    true => path_hasher.hash_children(&current_hash, sibling_hash)?,
+                    false => path_hasher.hash_children(sibling_hash, &current_hash)?,
+                };
+                match domain(level) {
+                    Some(domain) => path_hasher.hash_children(&domain, &parent),
+                    None => Ok(parent),
+                }
+            },
+        );
+        match candidate_root {
+            Ok(candidate_root) => is_equal_constant_time(&candidate_root, root),
+            Err(_) => false,
         }
+    }
+
+    /// Returns `true` if the leaf is in the tree of this Merkle path, and the root of that tree is
+    /// in the accumulator, as proven by `tree_root_path`.
+    ///
+    /// The accumulator is a Merkle tree whose leaf hashes are the roots of the inner trees,
+    /// i.e. the inner tree root is not rehashed as a leaf when verifying `tree_root_path`.
+    pub fn verify_in_accumulator<
+        LH: LeafHash<Hash = PH::Hash>,
+        PH: PathHash<Hash = Field<E>>,
+        const OUTER_DEPTH: u8,
+    >(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        accumulator_root: &PH::Hash,
+        tree_root_path: &MerklePath<E, OUTER_DEPTH>,
+        leaf: &LH::Leaf,
+    ) -> bool {
+        // Compute the root of the inner tree, and then the root of the accumulator.
+        match self
+            .compute_root(leaf_hasher, path_hasher, leaf)
+            .and_then(|tree_root| Ok(tree_root_path.compute_root_from_leaf_hash(path_hasher, tree_root)?))
+        {
+            Ok(candidate_root) => is_equal_constant_time(&candidate_root, accumulator_root),
+            Err(_) => false,
+        }
+    }
 
-        // Initialize a tracker for the current hash, by computing the leaf hash to start.
-        let mut current_hash = match leaf_hasher.hash_leaf(leaf) {
-            Ok(candidate_leaf_hash) => candidate_leaf_hash,
-            Err(error) => {
-                eprintln!("Failed to hash the Merkle leaf during verification: {error}");
-                return false;
-            }
+    /// Returns a receipt attesting that this Merkle path was checked against the given root and leaf.
+    ///
+    /// The receipt is a hash binding the leaf hash, the root, the verification outcome, the leaf index,
+    /// and the siblings. As such, two checks of the same path, root, and leaf produce the same receipt.
+    pub fn verification_receipt<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaf: &LH::Leaf,
+    ) -> Result<Field<E>> {
+        // Compute the leaf hash.
+        let leaf_hash = leaf_hasher.hash_leaf(leaf)?;
+        // Determine if the Merkle path is valid for the given root and leaf hash.
+        let is_valid = match self.compute_root_from_leaf_hash(path_hasher, leaf_hash) {
+            Ok(candidate_root) => is_equal_constant_time(&candidate_root, root),
+            Err(_) => false,
         };
 
-        // Compute the ordering of the current hash and sibling hash on each level.
+        // Bind the leaf hash, the root, and the verification outcome.
+        let mut receipt = path_hasher.hash_children(&leaf_hash, root)?;
+        receipt = path_hasher.hash_children(&receipt, &Field::from_u8(is_valid as u8))?;
+        // Bind the leaf index and the siblings.
+        receipt = path_hasher.hash_children(&receipt, &Field::from_u64(*self.leaf_index))?;
+        self.siblings.iter().try_fold(receipt, |receipt, sibling| path_hasher.hash_children(&receipt, sibling))
+    }
+
+    /// Returns a hash of the leaf index and siblings, as a succinct cryptographic tag for the Merkle path.
+    pub fn witness_hash<PH: PathHash<Hash = Field<E>>>(&self, path_hasher: &PH) -> Result<Field<E>> {
+        // Fold the leaf index with each of the siblings.
+        let leaf_index = Field::from_u64(*self.leaf_index);
+        self.siblings.iter().try_fold(leaf_index, |witness, sibling| path_hasher.hash_children(&witness, sibling))
+    }
+
+    /// Returns the root computed by folding the given leaf hash with the siblings of the Merkle path.
+    fn compute_root_from_leaf_hash<PH: PathHash<Hash = Field<E>>>(
+        &self,
+        path_hasher: &PH,
+        leaf_hash: Field<E>,
+    ) -> Result<Field<E>, MerklePathError> {
+        self.compute_root_wide(path_hasher, [leaf_hash]).map(|[root]| root)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<E: Environment, const DEPTH: u8, const WIDTH: usize> zeroize::Zeroize for MerklePath<E, DEPTH, WIDTH> {
+    /// Clears the leaf index and the siblings of the Merkle path.
+    fn zeroize(&mut self) {
+        self.leaf_index = U64::zero();
+        self.siblings.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<E: Environment, const DEPTH: u8, const WIDTH: usize> Drop for MerklePath<E, DEPTH, WIDTH> {
+    /// Clears the Merkle path on drop, as it may be part of a private witness.
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<E: Environment, const DEPTH: u8, const WIDTH: usize> zeroize::ZeroizeOnDrop for MerklePath<E, DEPTH, WIDTH> {}
+
+/// Returns `Ok(())` if a Merkle path with the given leaf index and number of sibling field elements
+/// is well-formed for a Merkle tree of the given depth, with node hashes of the given width.
+fn prevalidate_path(depth: u8, width: usize, leaf_index: u64, num_siblings: usize) -> Result<(), MerklePathError> {
+    // Ensure the leaf index is within the tree depth.
+    if (leaf_index as u128) >= (1u128 << depth) {
+        return Err(MerklePathError::LeafIndexOutOfBounds);
+    }
+    // Ensure the path length matches the expected depth.
+    if num_siblings != depth as usize * width {
+        return Err(MerklePathError::IncorrectPathLength { expected: depth as usize * width, found: num_siblings });
+    }
+    Ok(())
+}
+
+/// Returns the root computed by folding the given leaf hash with the given `(indicator, sibling)` levels,
+/// from the leaf level to the root level, where each node hash is `WIDTH` field elements wide.
+fn fold_levels<'a, E: Environment, PH: PathHash<Hash = Field<E>>, const WIDTH: usize>(
+    path_hasher: &PH,
+    leaf_hash: [Field<E>; WIDTH],
+    mut levels: impl Iterator<Item = (bool, &'a [Field<E>; WIDTH])>,
+) -> Result<[Field<E>; WIDTH], MerklePathError> {
+    levels.try_fold(leaf_hash, |current_hash, (indicator, sibling_hash)| {
         // If the indicator bit is `true`, then the ordering is (current_hash, sibling_hash).
         // If the indicator bit is `false`, then the ordering is (sibling_hash, current_hash).
-        let indicators = (0..DEPTH).map(|i| ((*self.leaf_index >> i) & 1) == 0);
-
-        // Check levels between leaf level and root.
-        for (indicator, sibling_hash) in indicators.zip_eq(&self.siblings) {
-            // Construct the ordering of the left & right child hash for this level.
-            let (left, right) = match indicator {
-                true => (current_hash, *sibling_hash),
-                false => (*sibling_hash, current_hash),
-            };
-            // Update the current hash for the next level.
-            match path_hasher.hash_children(&left, &right) {
-                Ok(hash) => current_hash = hash,
-                Err(error) => {
-                    eprintln!("Failed to hash the Merkle path during verification: {error}");
-                    return false;
-                }
-            }
-        }
+        let (left, right) = match indicator {
+            true => (&current_hash, sibling_hash),
+            false => (sibling_hash, &current_hash),
+        };
+        // Compute the hash for the next level.
+        path_hasher.hash_wide_children(left, right).map_err(MerklePathError::PathHashFailed)
+    })
+}
+
+/// Returns `true` if the given roots are equal. See `is_equal_constant_time_wide`.
+fn is_equal_constant_time<E: Environment>(first: &Field<E>, second: &Field<E>) -> bool {
+    is_equal_constant_time_wide(&[*first], &[*second])
+}
 
-        // Ensure the final hash matches the given root.
-        current_hash == *root
+/// Returns `true` if the given roots of `WIDTH` field elements are equal, by comparing their canonical
+/// byte encodings with `subtle::ConstantTimeEq`, which does not stop at the first differing byte.
+fn is_equal_constant_time_wide<E: Environment, const WIDTH: usize>(
+    first: &[Field<E>; WIDTH],
+    second: &[Field<E>; WIDTH],
+) -> bool {
+    let to_bytes = |root: &[Field<E>; WIDTH]| root.iter().map(|field| field.to_bytes_le()).collect::<Result<Vec<_>>>();
+    match (to_bytes(first), to_bytes(second)) {
+        (Ok(first), Ok(second)) => bool::from(first.concat().ct_eq(&second.concat())),
+        _ => false,
     }
 }
 
-impl<E: Environment, const DEPTH: u8> FromBytes for MerklePath<E, DEPTH> {
+impl<E: Environment, const DEPTH: u8, const WIDTH: usize> FromBytes for MerklePath<E, DEPTH, WIDTH> {
     /// Reads in a Merkle path from a buffer.
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the leaf index.
         let leaf_index = u64::read_le(&mut reader)?;
-        // Read the Merkle path siblings.
-        let siblings =
-            (0..DEPTH).map(|_| Ok(Field::new(FromBytes::read_le(&mut reader)?))).collect::<IoResult<Vec<_>>>()?;
+        // Read the Merkle path siblings, as `WIDTH` field elements for each sibling.
+        let siblings = (0..DEPTH as usize * WIDTH)
+            .map(|_| Ok(Field::new(FromBytes::read_le(&mut reader)?)))
+            .collect::<IoResult<Vec<_>>>()?;
         // Return the Merkle path.
         Self::try_from((U64::new(leaf_index), siblings)).map_err(error)
     }
 }
 
-impl<E: Environment, const DEPTH: u8> ToBytes for MerklePath<E, DEPTH> {
+impl<E: Environment, const DEPTH: u8, const WIDTH: usize> ToBytes for MerklePath<E, DEPTH, WIDTH> {
     /// Writes the Merkle path to a buffer.
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
@@ -139,8 +668,6 @@ impl<E: Environment, const DEPTH: u8> Serialize for MerklePath<E, DEPTH> {
 
 impl<'de, E: Environment, const DEPTH: u8> Deserialize<'de> for MerklePath<E, DEPTH> {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        // Compute the size for: u64 + (Field::SIZE_IN_BYTES * DEPTH).
-        let size = 8 + DEPTH as usize * (Field::<E>::size_in_bits() + 7) / 8;
-        FromBytesDeserializer::<Self>::deserialize(deserializer, "Merkle path", size)
+        FromBytesDeserializer::<Self>::deserialize(deserializer, "Merkle path", Self::size_in_bytes())
     }
 }
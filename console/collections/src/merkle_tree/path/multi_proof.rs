@@ -0,0 +1,170 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A Merkle proof for multiple leaves, which stores each sibling shared by their paths once.
+///
+/// A sibling is stored only if it cannot be derived from the proven leaves.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MerkleMultiProof<E: Environment, const DEPTH: u8> {
+    /// The sorted and distinct leaf indices for the proof.
+    leaf_indices: Vec<u64>,
+    /// The siblings that cannot be derived from the proven leaves, from the leaf level to the root level,
+    /// and in ascending order of position on each level.
+    siblings: Vec<Field<E>>,
+}
+
+impl<E: Environment, const DEPTH: u8> MerkleMultiProof<E, DEPTH> {
+    /// Returns a new multi-proof from the given Merkle paths, which must be from the same tree.
+    pub fn from_paths(paths: &[MerklePath<E, DEPTH>]) -> Result<Self> {
+        // Ensure there is at least one path.
+        ensure!(!paths.is_empty(), "Cannot construct a Merkle multi-proof without paths");
+        // Index the paths by leaf index, ensuring duplicate paths are identical.
+        let mut paths_by_index = BTreeMap::new();
+        for path in paths {
+            if let Some(previous) = paths_by_index.insert(*path.leaf_index(), path) {
+                ensure!(previous == path, "Found conflicting Merkle paths for leaf index {}", path.leaf_index());
+            }
+        }
+
+        // Initialize the known positions on the leaf level, mapped to a path through that position.
+        let mut known = paths_by_index.iter().map(|(index, path)| (*index, *path)).collect::<BTreeMap<_, _>>();
+        let mut siblings = Vec::new();
+        for level in 0..DEPTH as usize {
+            // Store the sibling for each known position, if the sibling is not also known.
+            for (position, path) in &known {
+                if !known.contains_key(&(position ^ 1)) {
+                    siblings.push(path.siblings()[level]);
+                }
+            }
+            // Compute the known positions on the next level.
+            known = known.into_iter().map(|(position, path)| (position >> 1, path)).collect();
+        }
+
+        Ok(Self { leaf_indices: paths_by_index.into_keys().collect(), siblings })
+    }
+
+    /// Returns the sorted and distinct leaf indices for the proof.
+    pub fn leaf_indices(&self) -> &[u64] {
+        &self.leaf_indices
+    }
+
+    /// Returns the siblings that cannot be derived from the proven leaves.
+    pub fn siblings(&self) -> &[Field<E>] {
+        &self.siblings
+    }
+
+    /// Returns `true` if the multi-proof is valid for the given root and `(leaf index, leaf)` pairs.
+    /// The leaves must be exactly those of the proof, in any order.
+    pub fn verify_multi<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaves: &[(u64, LH::Leaf)],
+    ) -> bool {
+        self.compute_root(leaf_hasher, path_hasher, leaves)
+            .map_or(false, |candidate_root| is_equal_constant_time(&candidate_root, root))
+    }
+
+    /// Returns `true` if the consecutive `leaves` are at the leaf indices `[start, start + leaves.len())`
+    /// of the Merkle tree with the given root.
+    ///
+    /// The `border_siblings` are the siblings of the subtrees spanned by the range, i.e. the siblings of a
+    /// multi-proof for the range, from the leaf level to the root level, and in ascending order of position
+    /// on each level. The spanning subtrees are reconstructed from the leaves, and the root is computed once.
+    /// A range that requires more border siblings than provided, or fewer, is rejected.
+    pub fn verify_consecutive_range<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        start: u64,
+        leaves: &[LH::Leaf],
+        border_siblings: &[Field<E>],
+    ) -> bool {
+        // Ensure the range is non-empty, and does not overflow.
+        let Some(end) = u64::try_from(leaves.len()).ok().and_then(|num_leaves| start.checked_add(num_leaves)) else {
+            return false;
+        };
+        if start == end {
+            return false;
+        }
+        // Construct the multi-proof for the range.
+        let proof = Self { leaf_indices: (start..end).collect(), siblings: border_siblings.to_vec() };
+        let leaves = (start..end).zip_eq(leaves.iter().cloned()).collect::<Vec<_>>();
+        // Compute the root of the range, and ensure it matches the given root.
+        proof
+            .compute_root(leaf_hasher, path_hasher, &leaves)
+            .map_or(false, |candidate_root| is_equal_constant_time(&candidate_root, root))
+    }
+
+    /// Returns the root of the multi-proof for the given `(leaf index, leaf)` pairs.
+    pub(super) fn compute_root<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        leaves: &[(u64, LH::Leaf)],
+    ) -> Result<Field<E>> {
+        // Ensure the leaf indices are within the tree depth.
+        ensure!(
+            self.leaf_indices.iter().all(|index| (*index as u128) < (1u128 << DEPTH)),
+            "Found an out of bounds Merkle leaf index"
+        );
+        // Ensure the leaves are exactly those of the proof.
+        let indices = leaves.iter().map(|(index, _)| *index).collect::<BTreeSet<_>>();
+        ensure!(
+            indices.len() == leaves.len() && indices.into_iter().eq(self.leaf_indices.iter().copied()),
+            "The given leaves do not match the Merkle multi-proof"
+        );
+
+        // Compute the leaf hashes on the leaf level.
+        let mut known = leaves
+            .iter()
+            .map(|(index, leaf)| Ok((*index, leaf_hasher.hash_leaf(leaf)?)))
+            .collect::<Result<BTreeMap<_, _>>>()?;
+        let mut siblings = self.siblings.iter();
+        for _ in 0..DEPTH {
+            let mut next = BTreeMap::new();
+            for (position, hash) in &known {
+                // Skip a right child whose left sibling is known, as the pair was hashed with the left child.
+                if position & 1 == 1 && known.contains_key(&(position - 1)) {
+                    continue;
+                }
+                // Retrieve the sibling, from the known positions or from the proof.
+                let sibling = match known.get(&(position ^ 1)) {
+                    Some(sibling) => sibling,
+                    None => siblings.next().ok_or_else(|| anyhow!("Missing a Merkle multi-proof sibling"))?,
+                };
+                // Compute the parent hash.
+                let parent = match position & 1 == 0 {
+                    true => path_hasher.hash_children(hash, sibling)?,
+                    false => path_hasher.hash_children(sibling, hash)?,
+                };
+                next.insert(position >> 1, parent);
+            }
+            known = next;
+        }
+        // Ensure all of the siblings were used.
+        ensure!(siblings.next().is_none(), "Found unused Merkle multi-proof siblings");
+
+        // Return the root.
+        match known.into_values().next() {
+            Some(root) => Ok(root),
+            None => bail!("Cannot compute the root of an empty Merkle multi-proof"),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::cmp::Ordering;
+
+/// A proof that a target is absent from a Merkle tree whose leaves are sorted,
+/// consisting of the Merkle paths for the two adjacent leaves on either side of the target.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NonMembershipProof<E: Environment, const DEPTH: u8> {
+    /// The Merkle path for the greatest leaf that is less than the target.
+    low_path: MerklePath<E, DEPTH>,
+    /// The Merkle path for the least leaf that is greater than the target.
+    high_path: MerklePath<E, DEPTH>,
+}
+
+impl<E: Environment, const DEPTH: u8> TryFrom<(MerklePath<E, DEPTH>, MerklePath<E, DEPTH>)>
+    for NonMembershipProof<E, DEPTH>
+{
+    type Error = Error;
+
+    /// Returns a new non-membership proof from the Merkle paths for two adjacent leaves.
+    fn try_from((low_path, high_path): (MerklePath<E, DEPTH>, MerklePath<E, DEPTH>)) -> Result<Self> {
+        // Ensure the leaf indices are adjacent.
+        ensure!(
+            (*low_path.leaf_index()).checked_add(1) == Some(*high_path.leaf_index()),
+            "The leaf indices of a non-membership proof must be adjacent"
+        );
+        Ok(Self { low_path, high_path })
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> NonMembershipProof<E, DEPTH> {
+    /// Returns the Merkle path for the greatest leaf that is less than the target.
+    pub const fn low_path(&self) -> &MerklePath<E, DEPTH> {
+        &self.low_path
+    }
+
+    /// Returns the Merkle path for the least leaf that is greater than the target.
+    pub const fn high_path(&self) -> &MerklePath<E, DEPTH> {
+        &self.high_path
+    }
+
+    /// Returns `true` if the target is absent from the Merkle tree with the given root, whose leaves
+    /// are sorted under `compare`. The `neighbors` are the low leaf and the high leaf of the proof.
+    ///
+    /// Both Merkle paths must be valid for the given root, their leaf indices must be adjacent,
+    /// and `low_leaf < target < high_leaf` must hold under `compare`.
+    ///
+    /// Hence, the absence of a target below the first leaf or above the last leaf cannot be proven,
+    /// as there is no leaf on one side of it. To prove the absence of any target, construct the tree
+    /// with sentinel leaves at either end, which are the least and greatest leaves under `compare`.
+    pub fn verify<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        (low_leaf, high_leaf): (&LH::Leaf, &LH::Leaf),
+        target: &LH::Leaf,
+        compare: impl Fn(&LH::Leaf, &LH::Leaf) -> Ordering,
+    ) -> bool {
+        // Ensure the leaf indices are adjacent.
+        (*self.low_path.leaf_index()).checked_add(1) == Some(*self.high_path.leaf_index())
+            // Ensure the target lies strictly between the neighbors.
+            && compare(low_leaf, target) == Ordering::Less
+            && compare(target, high_leaf) == Ordering::Less
+            // Ensure both neighbors are in the Merkle tree.
+            && self.low_path.verify(leaf_hasher, path_hasher, root, low_leaf)
+            && self.high_path.verify(leaf_hasher, path_hasher, root, high_leaf)
+    }
+}
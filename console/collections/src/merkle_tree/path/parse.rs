@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const DEPTH: u8> Parser for MerklePath<E, DEPTH> {
+    /// Parses a string into a Merkle path: `{ leaf_index, [sibling_0, ..., sibling_n] }`.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses a sanitized sibling: `sibling`.
+        fn parse_sibling<E: Environment>(string: &str) -> ParserResult<Field<E>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the sibling from the string.
+            let (string, sibling) = Field::parse(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Return the sibling.
+            Ok((string, sibling))
+        }
+
+        /// Parses a Merkle path into its components: `{ leaf_index, [sibling_0, ..., sibling_n] }`.
+        fn parse_components<E: Environment>(string: &str) -> ParserResult<(U64<E>, Vec<Field<E>>)> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the "{" from the string.
+            let (string, _) = tag("{")(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the leaf index from the string.
+            let (string, leaf_index) = U64::parse(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the "," from the string.
+            let (string, _) = tag(",")(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the "[" from the string.
+            let (string, _) = tag("[")(string)?;
+            // Parse the siblings from the string.
+            let (string, siblings) = separated_list1(tag(","), parse_sibling)(string)?;
+            // Parse the "]" from the string.
+            let (string, _) = tag("]")(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the "}" from the string.
+            let (string, _) = tag("}")(string)?;
+            // Return the components.
+            Ok((string, (leaf_index, siblings)))
+        }
+
+        // Parse the Merkle path, enforcing the leaf index bounds and the path length.
+        map_res(parse_components, Self::try_from)(string)
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> FromStr for MerklePath<E, DEPTH> {
+    type Err = Error;
+
+    /// Returns a Merkle path from a string literal.
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> Display for MerklePath<E, DEPTH> {
+    /// Prints the Merkle path as a string, i.e. `{ 5u64, [sibling_0, ..., sibling_n] }`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{{ {}, [{}] }}",
+            self.leaf_index,
+            self.siblings.iter().map(|sibling| sibling.to_string()).collect::<Vec<_>>().join(", ")
+        )
+    }
+}
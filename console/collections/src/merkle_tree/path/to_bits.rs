@@ -0,0 +1,35 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const DEPTH: u8> ToBits for MerklePath<E, DEPTH> {
+    /// Returns the Merkle path as a list of **little-endian** bits,
+    /// i.e. the leaf index as 64 bits, followed by the bits of each sibling in leaf-to-root order.
+    fn write_bits_le(&self, vec: &mut Vec<bool>) {
+        // Write the leaf index.
+        self.leaf_index.write_bits_le(vec);
+        // Write the siblings.
+        self.siblings.iter().for_each(|sibling| sibling.write_bits_le(vec));
+    }
+
+    /// Returns the Merkle path as a list of **big-endian** bits,
+    /// i.e. the leaf index as 64 bits, followed by the bits of each sibling in leaf-to-root order.
+    fn write_bits_be(&self, vec: &mut Vec<bool>) {
+        // Write the leaf index.
+        self.leaf_index.write_bits_be(vec);
+        // Write the siblings.
+        self.siblings.iter().for_each(|sibling| sibling.write_bits_be(vec));
+    }
+}
@@ -576,6 +576,80 @@ fn test_merkle_tree_depth_4_poseidon() -> Result<()> {
     )
 }
 
+#[test]
+fn test_merkle_tree_append_single_leaves() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut rng = TestRng::default();
+
+    // Sample enough leaves to fill a tree of depth 3.
+    let leaves = (0..8).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+
+    // Append the leaves one at a time, starting from an empty tree.
+    let mut merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 3>::new(&leaf_hasher, &path_hasher, &[])?;
+    for (num_leaves, leaf) in leaves.iter().enumerate() {
+        merkle_tree.append(&[leaf.clone()])?;
+        // Ensure the tree matches a full rebuild for the same leaves.
+        let expected =
+            MerkleTree::<CurrentEnvironment, LH, PH, 3>::new(&leaf_hasher, &path_hasher, &leaves[..=num_leaves])?;
+        assert_eq!(expected.root(), merkle_tree.root());
+        assert_eq!(expected.tree(), merkle_tree.tree());
+        assert_eq!(num_leaves + 1, merkle_tree.number_of_leaves());
+    }
+
+    // Ensure appending past the capacity of the tree fails, and leaves the tree unchanged.
+    let root = *merkle_tree.root();
+    assert!(merkle_tree.append(&[vec![Uniform::rand(&mut rng)]]).is_err());
+    assert_eq!(root, *merkle_tree.root());
+    assert_eq!(8, merkle_tree.number_of_leaves());
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_append_batch_matches_rebuild() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut rng = TestRng::default();
+
+    // Sample enough leaves to fill a tree of depth 6.
+    let leaves = (0..64).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+
+    for num_initial in [0, 1, 5, 16, 63] {
+        for num_additional in [1, 2, 7, 32, 64 - num_initial] {
+            let num_additional = num_additional.min(64 - num_initial);
+            let all_leaves = &leaves[..num_initial + num_additional];
+
+            // Append the additional leaves in a single batch.
+            let mut batched =
+                MerkleTree::<CurrentEnvironment, LH, PH, 6>::new(&leaf_hasher, &path_hasher, &leaves[..num_initial])?;
+            batched.append(&all_leaves[num_initial..])?;
+
+            // Append the additional leaves one at a time.
+            let mut single =
+                MerkleTree::<CurrentEnvironment, LH, PH, 6>::new(&leaf_hasher, &path_hasher, &leaves[..num_initial])?;
+            for leaf in &all_leaves[num_initial..] {
+                single.append(&[leaf.clone()])?;
+            }
+
+            // Ensure both match a full rebuild for the same leaves.
+            let expected = MerkleTree::<CurrentEnvironment, LH, PH, 6>::new(&leaf_hasher, &path_hasher, all_leaves)?;
+            assert_eq!(expected.root(), batched.root());
+            assert_eq!(expected.tree(), batched.tree());
+            assert_eq!(expected.root(), single.root());
+            assert_eq!(all_leaves.len(), batched.number_of_leaves());
+        }
+    }
+    Ok(())
+}
+
 /// Use `cargo test profiler --features timer` to run this test.
 #[ignore]
 #[test]
@@ -0,0 +1,103 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{BHP512, BHP1024, Poseidon};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+/// Runs the following test:
+/// 1. Append each leaf to an empty Merkle frontier.
+/// 2. Check that the root of the Merkle frontier matches the Merkle tree over the same leaves, after each append.
+/// 3. Check that appending a leaf to a full Merkle frontier fails.
+fn check_merkle_frontier<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+) -> Result<()> {
+    // Construct an empty Merkle frontier.
+    let mut frontier = MerkleFrontier::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher)?;
+    let merkle_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &[])?;
+    assert_eq!(merkle_tree.root(), frontier.root());
+
+    // Append each leaf, and check the root against the Merkle tree over the same leaves.
+    for (num_leaves, leaf) in leaves.iter().enumerate() {
+        frontier.append(leaf)?;
+        let merkle_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &leaves[..=num_leaves])?;
+        assert_eq!(merkle_tree.root(), frontier.root());
+        assert_eq!(num_leaves as u64 + 1, frontier.number_of_leaves());
+    }
+
+    // If the Merkle frontier is full, ensure appending another leaf fails and leaves the frontier unchanged.
+    if leaves.len() as u128 == 1u128 << DEPTH {
+        let root = *frontier.root();
+        assert!(frontier.append(&leaves[0]).is_err());
+        assert_eq!(root, *frontier.root());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merkle_frontier_bhp() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = BHP1024<CurrentEnvironment>;
+        type PH = BHP512<CurrentEnvironment>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        // Fill the Merkle frontier, for small depths.
+        let num_leaves = if DEPTH <= 4 { 1 << DEPTH } else { 17 };
+        let leaves = (0..num_leaves).map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le()).collect::<Vec<_>>();
+        check_merkle_frontier::<CurrentEnvironment, LH, PH, DEPTH>(&leaf_hasher, &path_hasher, &leaves)
+    }
+
+    let mut rng = TestRng::default();
+
+    // Ensure DEPTH = 0 fails.
+    assert!(run_test::<0>(&mut rng).is_err());
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 5, 16, 32, 64]);
+    Ok(())
+}
+
+#[test]
+fn test_merkle_frontier_poseidon() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = Poseidon<CurrentEnvironment, 4>;
+        type PH = Poseidon<CurrentEnvironment, 2>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        // Fill the Merkle frontier, for small depths.
+        let num_leaves = if DEPTH <= 5 { 1 << DEPTH } else { 33 };
+        let leaves = (0..num_leaves).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>();
+        check_merkle_frontier::<CurrentEnvironment, LH, PH, DEPTH>(&leaf_hasher, &path_hasher, &leaves)
+    }
+
+    let mut rng = TestRng::default();
+
+    // Ensure DEPTH = 0 fails.
+    assert!(run_test::<0>(&mut rng).is_err());
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 5, 6, 16, 32, 64]);
+    Ok(())
+}
@@ -15,6 +15,8 @@
 use super::*;
 
 mod append;
+mod frontier;
+mod path;
 mod remove;
 mod update;
 mod update_many;
@@ -0,0 +1,1616 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{BHP512, BHP1024, Poseidon};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+type LH = Poseidon<CurrentEnvironment, 4>;
+type PH = Poseidon<CurrentEnvironment, 2>;
+
+#[test]
+fn test_verification_hash_count() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+        let leaves = (0..1).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>();
+        let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::new(&leaf_hasher, &path_hasher, &leaves)?;
+        let path = merkle_tree.prove(0, &leaves[0])?;
+        assert_eq!(DEPTH as usize + 1, path.verification_hash_count());
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+    run_tests!(&mut rng, [1, 2, 3, 8, 16, 32, 64]);
+    Ok(())
+}
+
+#[test]
+fn test_build_with_index() -> Result<()> {
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    // Sample leaves, and duplicate a few of them.
+    let mut leaves = (0..5).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    leaves.push(leaves[1].clone());
+    leaves.push(leaves[3].clone());
+    leaves.push(leaves[1].clone());
+
+    // Construct the Merkle tree and the index.
+    let (merkle_tree, positions) =
+        MerkleTree::<CurrentEnvironment, LH, PH, 8>::build_with_index(&leaf_hasher, &path_hasher, &leaves)?;
+    assert_eq!(5, positions.len());
+
+    // Ensure the duplicated leaves list all of their positions.
+    assert_eq!(vec![1, 5, 7], positions[&leaf_hasher.hash_leaf(&leaves[1])?]);
+    assert_eq!(vec![3, 6], positions[&leaf_hasher.hash_leaf(&leaves[3])?]);
+    assert_eq!(vec![0], positions[&leaf_hasher.hash_leaf(&leaves[0])?]);
+
+    // Ensure the proof at each position verifies.
+    for indices in positions.values() {
+        for index in indices {
+            let index = usize::try_from(*index)?;
+            let leaf = &leaves[index];
+            let path = merkle_tree.prove(index, leaf)?;
+            assert!(merkle_tree.verify(&path, merkle_tree.root(), leaf));
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_rotate() -> Result<()> {
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    // Construct a full Merkle tree, and a copy with its leaves shifted by 2.
+    let leaves = (0..8).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let mut rotated_leaves = leaves.clone();
+    rotated_leaves.rotate_right(2);
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 3>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let rotated_tree = MerkleTree::<CurrentEnvironment, LH, PH, 3>::new(&leaf_hasher, &path_hasher, &rotated_leaves)?;
+
+    for (leaf_index, leaf) in leaves.iter().enumerate() {
+        let path = merkle_tree.prove(leaf_index, leaf)?;
+        // Retrieve the upper siblings from the rotated tree.
+        let expected = rotated_tree.prove((leaf_index + 2) % 8, leaf)?;
+        let upper_siblings = expected.siblings()[1..].to_vec();
+
+        // Rotate the path, and ensure it verifies against the rotated tree.
+        let rotated = path.rotate(2, upper_siblings.clone())?;
+        assert_eq!(expected, rotated);
+        assert!(rotated_tree.verify(&rotated, rotated_tree.root(), leaf));
+        assert!(!merkle_tree.verify(&rotated, merkle_tree.root(), leaf));
+
+        // Ensure rotating back by a negative offset recovers the original leaf index.
+        let original = rotated.rotate(-2, path.siblings()[1..].to_vec())?;
+        assert_eq!(path, original);
+
+        // Ensure rotating by an incorrect number of upper siblings fails.
+        assert!(path.rotate(2, upper_siblings[1..].to_vec()).is_err());
+        assert!(path.rotate(1, upper_siblings).is_err());
+        // Ensure rotating by the capacity preserves the path.
+        assert_eq!(path, path.rotate(8, vec![])?);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_reverify_proofs() -> Result<()> {
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+    let migrated_path_hasher = PH::setup("AleoMerkleTreeTest2")?;
+
+    // Construct the Merkle tree, and issue a proof for each leaf.
+    let leaves = (0..10).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 8>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let proofs = leaves
+        .iter()
+        .enumerate()
+        .map(|(leaf_index, leaf)| Ok((merkle_tree.prove(leaf_index, leaf)?, leaf.clone())))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(vec![true; leaves.len()], merkle_tree.reverify_proofs(&proofs));
+
+    // Migrate the Merkle tree to a new path hasher.
+    let migrated_tree = MerkleTree::<CurrentEnvironment, LH, PH, 8>::new(&leaf_hasher, &migrated_path_hasher, &leaves)?;
+    // Ensure the pre-migration proofs fail.
+    assert_eq!(vec![false; leaves.len()], migrated_tree.reverify_proofs(&proofs));
+
+    // Ensure freshly-issued proofs pass.
+    let fresh_proofs = leaves
+        .iter()
+        .enumerate()
+        .map(|(leaf_index, leaf)| Ok((migrated_tree.prove(leaf_index, leaf)?, leaf.clone())))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(vec![true; leaves.len()], migrated_tree.reverify_proofs(&fresh_proofs));
+    Ok(())
+}
+
+#[test]
+fn test_verify_with_leaf_domain() -> Result<()> {
+    type LH = BHP1024<CurrentEnvironment>;
+    type PH = BHP512<CurrentEnvironment>;
+
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    // Sample leaves, and construct the tree over the leaves in domain A.
+    let (domain_a, domain_b) = (b"domain_a", b"domain_b");
+    let leaves = (0..5).map(|_| Field::<CurrentEnvironment>::rand(&mut rng).to_bits_le()).collect::<Vec<_>>();
+    let domain_leaves = leaves
+        .iter()
+        .map(|leaf| domain_a.to_bits_le().into_iter().chain(leaf.iter().copied()).collect())
+        .collect::<Vec<Vec<bool>>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 4>::new(&leaf_hasher, &path_hasher, &domain_leaves)?;
+
+    for (i, (leaf, domain_leaf)) in leaves.iter().zip_eq(&domain_leaves).enumerate() {
+        let path = merkle_tree.prove(i, domain_leaf)?;
+        // Ensure the path verifies under domain A.
+        assert!(path.verify_with_leaf_domain(&leaf_hasher, &path_hasher, merkle_tree.root(), domain_a, leaf));
+        // Ensure the path does not verify under domain B.
+        assert!(!path.verify_with_leaf_domain(&leaf_hasher, &path_hasher, merkle_tree.root(), domain_b, leaf));
+        // Ensure the path does not verify without a domain.
+        assert!(!path.verify_with_leaf_domain(&leaf_hasher, &path_hasher, merkle_tree.root(), &[], leaf));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_leaf_ordering_commitment() -> Result<()> {
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+    let hasher = Poseidon::<CurrentEnvironment, 2>::setup("AleoMerkleTreeTest2")?;
+
+    // Sample leaves, and swap two of them.
+    let leaves = (0..5).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let mut swapped_leaves = leaves.clone();
+    swapped_leaves.swap(1, 3);
+
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 8>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let swapped_tree = MerkleTree::<CurrentEnvironment, LH, PH, 8>::new(&leaf_hasher, &path_hasher, &swapped_leaves)?;
+
+    // Ensure both trees commit to the same set of leaf hashes.
+    let mut leaf_hashes = merkle_tree.leaf_hashes()?.to_vec();
+    let mut swapped_leaf_hashes = swapped_tree.leaf_hashes()?.to_vec();
+    leaf_hashes.sort_unstable();
+    swapped_leaf_hashes.sort_unstable();
+    assert_eq!(leaf_hashes, swapped_leaf_hashes);
+
+    // Ensure the commitment is deterministic, and distinct from the Merkle root.
+    let commitment = merkle_tree.leaf_ordering_commitment(&hasher)?;
+    assert_eq!(commitment, merkle_tree.leaf_ordering_commitment(&hasher)?);
+    assert_ne!(commitment, *merkle_tree.root());
+
+    // Ensure reordering the leaves changes the commitment.
+    assert_ne!(commitment, swapped_tree.leaf_ordering_commitment(&hasher)?);
+    Ok(())
+}
+
+#[test]
+fn test_verify_in_accumulator() -> Result<()> {
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    // Construct four inner trees.
+    let leaves = (0..4).map(|_| (0..3).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>()).collect::<Vec<_>>();
+    let trees = leaves
+        .iter()
+        .map(|leaves| MerkleTree::<CurrentEnvironment, LH, PH, 4>::new(&leaf_hasher, &path_hasher, leaves))
+        .collect::<Result<Vec<_>>>()?;
+
+    // Commit to the roots of the inner trees in an outer tree of depth 2.
+    let roots = trees.iter().map(|tree| *tree.root()).collect::<Vec<_>>();
+    let level = [path_hasher.hash_children(&roots[0], &roots[1])?, path_hasher.hash_children(&roots[2], &roots[3])?];
+    let accumulator_root = path_hasher.hash_children(&level[0], &level[1])?;
+
+    for (i, (tree, leaves)) in trees.iter().zip_eq(&leaves).enumerate() {
+        // Construct the path for the inner tree root in the outer tree.
+        let tree_root_path = MerklePath::<CurrentEnvironment, 2>::try_from((U64::new(i as u64), vec![
+            roots[i ^ 1],
+            level[(i >> 1) ^ 1],
+        ]))?;
+
+        for (j, leaf) in leaves.iter().enumerate() {
+            let path = tree.prove(j, leaf)?;
+            // Ensure the leaf verifies through both levels.
+            assert!(path.verify_in_accumulator(&leaf_hasher, &path_hasher, &accumulator_root, &tree_root_path, leaf));
+            // Ensure the leaf does not verify against the inner tree root as the accumulator root.
+            assert!(!path.verify_in_accumulator(&leaf_hasher, &path_hasher, tree.root(), &tree_root_path, leaf));
+            // Ensure a leaf from a different inner tree does not verify.
+            let other_leaf = &leaves[(j + 1) % leaves.len()];
+            assert!(!path.verify_in_accumulator(
+                &leaf_hasher,
+                &path_hasher,
+                &accumulator_root,
+                &tree_root_path,
+                other_leaf
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_memory_footprint() -> Result<()> {
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let hash_size = std::mem::size_of::<Field<CurrentEnvironment>>();
+    let mut previous_footprint = None;
+
+    for num_leaves in [8, 16, 32, 64] {
+        let leaves = (0..num_leaves).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+        let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 10>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+        // Ensure the footprint accounts for the leaf hashes and the internal nodes.
+        let footprint = merkle_tree.memory_footprint();
+        assert_eq!((2 * num_leaves - 1) * hash_size, footprint);
+
+        // Ensure the footprint doubles (plus one node) when the number of leaves doubles.
+        if let Some(previous_footprint) = previous_footprint {
+            assert_eq!(2 * previous_footprint + hash_size, footprint);
+        }
+        previous_footprint = Some(footprint);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_verification_receipt() -> Result<()> {
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..5).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 8>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let root = merkle_tree.root();
+
+    let path = merkle_tree.prove(2, &leaves[2])?;
+    assert!(path.verify(&leaf_hasher, &path_hasher, root, &leaves[2]));
+
+    // Ensure two checks of the same path, root, and leaf produce the same receipt.
+    let receipt = path.verification_receipt(&leaf_hasher, &path_hasher, root, &leaves[2])?;
+    assert_eq!(receipt, path.verification_receipt(&leaf_hasher, &path_hasher, root, &leaves[2])?);
+
+    // Ensure changing the root changes the receipt.
+    let other_root = Field::rand(&mut rng);
+    assert_ne!(receipt, path.verification_receipt(&leaf_hasher, &path_hasher, &other_root, &leaves[2])?);
+    // Ensure changing the leaf changes the receipt.
+    assert_ne!(receipt, path.verification_receipt(&leaf_hasher, &path_hasher, root, &leaves[3])?);
+    // Ensure changing the path changes the receipt.
+    let other_path = merkle_tree.prove(3, &leaves[3])?;
+    assert_ne!(receipt, other_path.verification_receipt(&leaf_hasher, &path_hasher, root, &leaves[2])?);
+    Ok(())
+}
+
+#[test]
+fn test_prove_top_down() -> Result<()> {
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..11).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 6>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let root = merkle_tree.root();
+
+    for (i, leaf) in leaves.iter().enumerate() {
+        let path = merkle_tree.prove(i, leaf)?;
+        let top_down_path = merkle_tree.prove_top_down(i, leaf)?;
+
+        // Ensure the top-down path is the reverse of the standard path.
+        assert_eq!(path.leaf_index(), top_down_path.leaf_index());
+        assert_eq!(
+            path.siblings().iter().rev().collect::<Vec<_>>(),
+            top_down_path.siblings().iter().collect::<Vec<_>>()
+        );
+
+        // Ensure the top-down path verifies with the top-down verifier.
+        assert!(top_down_path.verify_top_down(&leaf_hasher, &path_hasher, root, leaf));
+        // Ensure the top-down path does not verify with the standard verifier, and vice versa.
+        assert!(!top_down_path.verify(&leaf_hasher, &path_hasher, root, leaf));
+        assert!(!path.verify_top_down(&leaf_hasher, &path_hasher, root, leaf));
+        // Ensure the top-down path does not verify for a different leaf.
+        assert!(!top_down_path.verify_top_down(&leaf_hasher, &path_hasher, root, &leaves[(i + 1) % leaves.len()]));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_witness_hash() -> Result<()> {
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..7).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 8>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+    let paths = leaves.iter().enumerate().map(|(i, leaf)| merkle_tree.prove(i, leaf)).collect::<Result<Vec<_>>>()?;
+    let witnesses = paths.iter().map(|path| path.witness_hash(&path_hasher)).collect::<Result<Vec<_>>>()?;
+
+    for (i, (path, witness)) in paths.iter().zip_eq(&witnesses).enumerate() {
+        // Ensure equal paths share the witness hash.
+        assert_eq!(*witness, merkle_tree.prove(i, &leaves[i])?.witness_hash(&path_hasher)?);
+        // Ensure the witness hash is deterministic across a serialization round-trip.
+        let candidate = MerklePath::<CurrentEnvironment, 8>::from_bytes_le(&path.to_bytes_le()?)?;
+        assert_eq!(*witness, candidate.witness_hash(&path_hasher)?);
+        // Ensure different paths have different witness hashes.
+        for (j, other_witness) in witnesses.iter().enumerate() {
+            if i != j {
+                assert_ne!(witness, other_witness);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_prove_where() -> Result<()> {
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    // Construct the leaves with the values 0 to 10.
+    let leaves = (0..11u64).map(|i| vec![Field::<CurrentEnvironment>::from_u64(i)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 8>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+    // Prove all even-valued leaves.
+    let is_even = |leaf: &Vec<Field<CurrentEnvironment>>| !leaf[0].to_bits_le()[0];
+    let proofs = merkle_tree.prove_where(&leaves, is_even)?;
+
+    // Ensure each even-valued leaf is proven, and each proof verifies.
+    assert_eq!(vec![0, 2, 4, 6, 8, 10], proofs.iter().map(|(leaf_index, _)| *leaf_index).collect::<Vec<_>>());
+    for (leaf_index, path) in &proofs {
+        let leaf = &leaves[usize::try_from(*leaf_index)?];
+        assert_eq!(*leaf_index, *path.leaf_index());
+        assert!(merkle_tree.verify(path, merkle_tree.root(), leaf));
+    }
+
+    // Ensure a predicate matching no leaves returns no proofs.
+    assert!(merkle_tree.prove_where(&leaves, |_| false)?.is_empty());
+    // Ensure the wrong number of leaves fails.
+    assert!(merkle_tree.prove_where(&leaves[1..], is_even).is_err());
+    Ok(())
+}
+
+/// A leaf hasher that always fails, for testing error handling.
+#[derive(Clone)]
+struct FailingLeafHash;
+
+impl LeafHash for FailingLeafHash {
+    type Hash = Field<CurrentEnvironment>;
+    type Leaf = Vec<Field<CurrentEnvironment>>;
+
+    fn hash_leaf(&self, _leaf: &Self::Leaf) -> Result<Self::Hash> {
+        bail!("This leaf hasher always fails")
+    }
+}
+
+/// A path hasher that always fails, for testing error handling.
+#[derive(Clone)]
+struct FailingPathHash;
+
+impl PathHash for FailingPathHash {
+    type Hash = Field<CurrentEnvironment>;
+
+    fn hash_children(&self, _left: &Self::Hash, _right: &Self::Hash) -> Result<Self::Hash> {
+        bail!("This path hasher always fails")
+    }
+}
+
+#[test]
+fn test_ensure_verifies() -> Result<()> {
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..5).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 8>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let root = merkle_tree.root();
+
+    // Ensure a valid path succeeds.
+    let path = merkle_tree.prove(1, &leaves[1])?;
+    path.ensure_verifies(&leaf_hasher, &path_hasher, root, &leaves[1])?;
+
+    // Ensure the wrong leaf fails with a root mismatch.
+    let error = path.ensure_verifies(&leaf_hasher, &path_hasher, root, &leaves[2]).unwrap_err();
+    assert!(error.to_string().starts_with("Merkle path root mismatch"));
+    // Ensure the wrong root fails with a root mismatch.
+    let error = path.ensure_verifies(&leaf_hasher, &path_hasher, &Field::rand(&mut rng), &leaves[1]).unwrap_err();
+    assert!(error.to_string().starts_with("Merkle path root mismatch"));
+
+    // Ensure a leaf hashing failure fails as a hashing failure.
+    let error = path.ensure_verifies(&FailingLeafHash, &path_hasher, root, &leaves[1]).unwrap_err();
+    assert!(error.to_string().starts_with("Failed to hash the Merkle leaf"));
+    Ok(())
+}
+
+#[test]
+fn test_verify_and_count() -> Result<()> {
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..8).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 8>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let paths = leaves.iter().enumerate().map(|(i, leaf)| merkle_tree.prove(i, leaf)).collect::<Result<Vec<_>>>()?;
+
+    let counter = std::sync::atomic::AtomicU64::new(0);
+
+    // Verify each path concurrently, against the correct leaf and an incorrect leaf.
+    std::thread::scope(|scope| {
+        for (i, path) in paths.iter().enumerate() {
+            let (merkle_tree, leaves, counter) = (&merkle_tree, &leaves, &counter);
+            scope.spawn(move || {
+                assert!(merkle_tree.verify_and_count(path, &leaves[i], counter));
+                assert!(!merkle_tree.verify_and_count(path, &leaves[(i + 1) % leaves.len()], counter));
+            });
+        }
+    });
+
+    // Ensure only the successful verifications were counted.
+    assert_eq!(leaves.len() as u64, counter.load(std::sync::atomic::Ordering::SeqCst));
+    Ok(())
+}
+
+#[test]
+fn test_window_root() -> Result<()> {
+    type MT = MerkleTree<CurrentEnvironment, LH, PH, 8>;
+
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..13).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+
+    for window in [0, 1, 2, 3, 4, 5, 8] {
+        for start in 0..=leaves.len() {
+            // Ensure the window root matches a new Merkle tree over the window, which is truncated near the end.
+            let end = (start + window).min(leaves.len());
+            let expected = MT::new(&leaf_hasher, &path_hasher, &leaves[start..end])?;
+            let candidate = MT::window_root(&leaf_hasher, &path_hasher, &leaves, start, window)?;
+            assert_eq!(*expected.root(), candidate, "start = {start}, window = {window}");
+        }
+    }
+
+    // Ensure a window starting past the last leaf fails.
+    assert!(MT::window_root(&leaf_hasher, &path_hasher, &leaves, leaves.len() + 1, 1).is_err());
+    // Ensure a window exceeding the depth fails.
+    let leaves = (0..5).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    assert!(
+        MerkleTree::<CurrentEnvironment, LH, PH, 2>::window_root(&leaf_hasher, &path_hasher, &leaves, 0, 5).is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_rebase() -> Result<()> {
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..11).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 6>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+    for changed_leaf_index in 0..leaves.len() {
+        // Update the changed leaf.
+        let new_leaf = vec![Field::rand(&mut rng)];
+        let mut new_tree = merkle_tree.clone();
+        new_tree.update(changed_leaf_index, &new_leaf)?;
+
+        let old_path = merkle_tree.prove(changed_leaf_index, &leaves[changed_leaf_index])?;
+        let new_path = new_tree.prove(changed_leaf_index, &new_leaf)?;
+        let new_leaf_hash = leaf_hasher.hash_leaf(&new_leaf)?;
+
+        for (i, leaf) in leaves.iter().enumerate().filter(|(i, _)| *i != changed_leaf_index) {
+            // Ensure the stale path does not verify against the new root.
+            let mut path = merkle_tree.prove(i, leaf)?;
+            assert!(!path.verify(&leaf_hasher, &path_hasher, new_tree.root(), leaf));
+
+            // Ensure the rebased path verifies against the new root.
+            path.rebase(&path_hasher, changed_leaf_index as u64, &old_path, &new_path, new_leaf_hash)?;
+            assert!(path.verify(&leaf_hasher, &path_hasher, new_tree.root(), leaf));
+            assert_eq!(new_tree.prove(i, leaf)?, path);
+        }
+    }
+
+    // Ensure paths for a different leaf fail.
+    let old_path = merkle_tree.prove(1, &leaves[1])?;
+    let mut path = merkle_tree.prove(0, &leaves[0])?;
+    assert!(path.rebase(&path_hasher, 2, &old_path, &old_path, Field::rand(&mut rng)).is_err());
+    // Ensure a path from a different tree fails.
+    let other_leaves = (0..11).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let other_tree = MerkleTree::<CurrentEnvironment, LH, PH, 6>::new(&leaf_hasher, &path_hasher, &other_leaves)?;
+    let mut path = other_tree.prove(0, &other_leaves[0])?;
+    assert!(path.rebase(&path_hasher, 1, &old_path, &old_path, Field::rand(&mut rng)).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_batch_verify() -> Result<()> {
+    type MP = MerklePath<CurrentEnvironment, 8>;
+
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..9).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 8>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let root = merkle_tree.root();
+    let paths = leaves.iter().enumerate().map(|(i, leaf)| merkle_tree.prove(i, leaf)).collect::<Result<Vec<_>>>()?;
+
+    // Ensure an empty batch is valid.
+    assert!(MP::batch_verify(&[], &leaf_hasher, &path_hasher, root));
+
+    // Ensure a batch of valid paths, including duplicate leaf indices, is valid.
+    let mut batch = paths.iter().zip_eq(&leaves).collect::<Vec<_>>();
+    batch.push((&paths[3], &leaves[3]));
+    assert!(MP::batch_verify(&batch, &leaf_hasher, &path_hasher, root));
+
+    // Ensure a batch with one invalid path is invalid.
+    batch.push((&paths[3], &leaves[4]));
+    assert!(!MP::batch_verify(&batch, &leaf_hasher, &path_hasher, root));
+    // Ensure a batch of valid paths against the wrong root is invalid.
+    assert!(!MP::batch_verify(&batch[..1], &leaf_hasher, &path_hasher, &Field::rand(&mut rng)));
+    Ok(())
+}
+
+#[test]
+fn test_proof_archive() -> Result<()> {
+    type MT = MerkleTree<CurrentEnvironment, LH, PH, 8>;
+
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..100).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MT::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+    // Export an archive of scattered leaf indices, including a duplicate.
+    let indices = [97, 3, 4, 5, 40, 41, 63, 0, 99, 5];
+    let archive = merkle_tree.export_proof_archive(&indices)?;
+
+    // Ensure each reconstructed path verifies, in ascending order of leaf index.
+    let paths = MT::import_proof_archive(&archive)?;
+    let expected_indices = [0, 3, 4, 5, 40, 41, 63, 97, 99];
+    assert_eq!(expected_indices.len(), paths.len());
+    for (index, path) in expected_indices.into_iter().zip_eq(&paths) {
+        assert_eq!(merkle_tree.prove(index, &leaves[index])?, *path);
+        assert!(merkle_tree.verify(path, merkle_tree.root(), &leaves[index]));
+    }
+
+    // Ensure the archive is smaller than the naive concatenation of the paths.
+    let naive_size = paths.iter().map(|path| Ok(path.to_bytes_le()?.len())).sum::<Result<usize>>()?;
+    assert!(archive.len() < naive_size, "{} >= {naive_size}", archive.len());
+
+    // Ensure an empty archive round-trips.
+    assert!(MT::import_proof_archive(&merkle_tree.export_proof_archive(&[])?)?.is_empty());
+    // Ensure out of bounds leaf indices fail.
+    assert!(merkle_tree.export_proof_archive(&[100]).is_err());
+    // Ensure a truncated or extended archive fails.
+    assert!(MT::import_proof_archive(&archive[..archive.len() - 1]).is_err());
+    assert!(MT::import_proof_archive(&[archive.as_slice(), &[0]].concat()).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_compute_root() -> Result<()> {
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..6).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 8>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+    for (i, leaf) in leaves.iter().enumerate() {
+        let path = merkle_tree.prove(i, leaf)?;
+        // Ensure the computed root matches the Merkle tree root.
+        assert_eq!(*merkle_tree.root(), path.compute_root(&leaf_hasher, &path_hasher, leaf)?);
+        // Ensure the computed root for a different leaf does not match.
+        let other_leaf = &leaves[(i + 1) % leaves.len()];
+        assert_ne!(*merkle_tree.root(), path.compute_root(&leaf_hasher, &path_hasher, other_leaf)?);
+    }
+
+    // Ensure a leaf hashing failure returns an error.
+    let path = merkle_tree.prove(0, &leaves[0])?;
+    assert!(path.compute_root(&FailingLeafHash, &path_hasher, &leaves[0]).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_verify_with_reason() -> Result<()> {
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..5).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 8>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let root = merkle_tree.root();
+    let path = merkle_tree.prove(4, &leaves[4])?;
+
+    // Ensure a valid path succeeds.
+    assert!(path.verify_with_reason(&leaf_hasher, &path_hasher, root, &leaves[4]).is_ok());
+
+    // Ensure the wrong leaf fails with a root mismatch.
+    let result = path.verify_with_reason(&leaf_hasher, &path_hasher, root, &leaves[0]);
+    assert!(matches!(result, Err(MerklePathError::RootMismatch)));
+    assert!(!path.verify(&leaf_hasher, &path_hasher, root, &leaves[0]));
+
+    // Ensure a leaf hashing failure is reported as such.
+    let result = path.verify_with_reason(&FailingLeafHash, &path_hasher, root, &leaves[4]);
+    assert!(matches!(result, Err(MerklePathError::LeafHashFailed(_))));
+    assert!(!path.verify(&FailingLeafHash, &path_hasher, root, &leaves[4]));
+
+    // Ensure a path hashing failure is reported as such.
+    let result = path.verify_with_reason(&leaf_hasher, &FailingPathHash, root, &leaves[4]);
+    assert!(matches!(result, Err(MerklePathError::PathHashFailed(_))));
+    assert!(!path.verify(&leaf_hasher, &FailingPathHash, root, &leaves[4]));
+    Ok(())
+}
+
+#[test]
+fn test_iter_levels() -> Result<()> {
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..11).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 6>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+    for (i, leaf) in leaves.iter().enumerate() {
+        let path = merkle_tree.prove(i, leaf)?;
+
+        // Ensure the levels are ordered from the leaf level to the root level.
+        let levels = path.iter_levels().collect::<Vec<_>>();
+        assert_eq!(6, levels.len());
+        for (level, (is_left, sibling)) in levels.iter().enumerate() {
+            assert_eq!((i >> level) & 1 == 0, *is_left);
+            assert_eq!(&path.siblings()[level], *sibling);
+        }
+
+        // Ensure the intermediate hashes reconstruct the root.
+        let root =
+            levels.into_iter().try_fold(
+                leaf_hasher.hash_leaf(leaf)?,
+                |current_hash, (is_left, sibling)| match is_left {
+                    true => path_hasher.hash_children(&current_hash, sibling),
+                    false => path_hasher.hash_children(sibling, &current_hash),
+                },
+            )?;
+        assert_eq!(*merkle_tree.root(), root);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_compressed_path() -> Result<()> {
+    type MP = MerklePath<CurrentEnvironment, 32>;
+
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    // Construct a sparse tree, where most siblings are the empty hash.
+    let leaves = (0..3).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 32>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let empty_hashes = vec![*merkle_tree.empty_hash(); 32];
+
+    for (i, leaf) in leaves.iter().enumerate() {
+        let path = merkle_tree.prove(i, leaf)?;
+        let compressed = path.compress(&empty_hashes)?;
+
+        // Ensure only the non-empty siblings are stored.
+        let num_present = path.siblings().iter().filter(|sibling| *sibling != merkle_tree.empty_hash()).count();
+        assert_eq!(num_present, compressed.siblings().len());
+        assert_eq!(32 - num_present, compressed.mask().count_ones() as usize);
+
+        // Ensure the decompressed path matches, and verifies.
+        let decompressed = compressed.decompress(&empty_hashes)?;
+        assert_eq!(path, decompressed);
+        assert!(decompressed.verify(&leaf_hasher, &path_hasher, merkle_tree.root(), leaf));
+
+        // Ensure the bytes round-trip, and are smaller than the uncompressed path.
+        let bytes = compressed.to_bytes_le()?;
+        assert_eq!(compressed, CompressedMerklePath::read_le(&bytes[..])?);
+        assert!(bytes.len() < path.to_bytes_le()?.len());
+
+        // Ensure the wrong number of empty hashes fails.
+        assert!(path.compress(&empty_hashes[1..]).is_err());
+        assert!(compressed.decompress(&empty_hashes[1..]).is_err());
+    }
+
+    // Ensure a path with no empty siblings is not compressed.
+    let path = MP::try_from((U64::new(5), (0..32).map(|_| Field::rand(&mut rng)).collect()))?;
+    let compressed = path.compress(&empty_hashes)?;
+    assert_eq!(0, compressed.mask());
+    assert_eq!(path, compressed.decompress(&empty_hashes)?);
+
+    // Ensure a mask that does not match the siblings fails.
+    assert!(CompressedMerklePath::<CurrentEnvironment, 32>::try_from((U64::new(0), 1, vec![])).is_err());
+    assert!(CompressedMerklePath::<CurrentEnvironment, 4>::try_from((U64::new(0), 1 << 4, vec![])).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_dynamic_path() -> Result<()> {
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..7).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let tree_a = MerkleTree::<CurrentEnvironment, LH, PH, 4>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let tree_b = MerkleTree::<CurrentEnvironment, LH, PH, 16>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+    // Ensure paths of different depths are handled by the same type.
+    let paths = [
+        (MerklePathDyn::try_from(tree_a.prove(3, &leaves[3])?)?, *tree_a.root()),
+        (MerklePathDyn::try_from(tree_b.prove(3, &leaves[3])?)?, *tree_b.root()),
+    ];
+    for (path, root) in &paths {
+        assert!(path.verify(&leaf_hasher, &path_hasher, root, &leaves[3]));
+        assert!(!path.verify(&leaf_hasher, &path_hasher, root, &leaves[4]));
+        // Ensure the bytes round-trip.
+        assert_eq!(*path, MerklePathDyn::read_le(&path.to_bytes_le()?[..])?);
+    }
+    assert_eq!(4, paths[0].0.depth());
+    assert_eq!(16, paths[1].0.depth());
+
+    // Ensure the conversion to a const-generic path checks the depth.
+    assert_eq!(tree_a.prove(3, &leaves[3])?, paths[0].0.to_const::<4>()?);
+    assert!(paths[0].0.to_const::<16>().is_err());
+    assert_eq!(tree_b.prove(3, &leaves[3])?, paths[1].0.to_const::<16>()?);
+
+    // Ensure the depth, leaf index, and path length are validated.
+    let siblings = paths[0].0.siblings().to_vec();
+    assert!(MerklePathDyn::<CurrentEnvironment>::try_from((0, U64::new(0), vec![])).is_err());
+    assert!(MerklePathDyn::<CurrentEnvironment>::try_from((4, U64::new(16), siblings.clone())).is_err());
+    assert!(MerklePathDyn::<CurrentEnvironment>::try_from((5, U64::new(0), siblings)).is_err());
+    assert!(MerklePathDyn::<CurrentEnvironment>::read_le(&[65u8][..]).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_size_in_bytes() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+        let leaves = (0..2).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>();
+        let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::new(&leaf_hasher, &path_hasher, &leaves)?;
+        let path = merkle_tree.prove(1, &leaves[1])?;
+
+        // Ensure the size matches the serialized bytes.
+        let bytes = path.to_bytes_le()?;
+        assert_eq!(MerklePath::<CurrentEnvironment, DEPTH>::size_in_bytes(), bytes.len());
+
+        // Ensure the path round-trips through bincode and JSON.
+        let candidate: MerklePath<CurrentEnvironment, DEPTH> = bincode::deserialize(&bincode::serialize(&path)?)?;
+        assert_eq!(path, candidate);
+        let candidate: MerklePath<CurrentEnvironment, DEPTH> = serde_json::from_str(&serde_json::to_string(&path)?)?;
+        assert_eq!(path, candidate);
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+    run_tests!(&mut rng, [1, 2, 3, 8, 16, 32, 64]);
+    Ok(())
+}
+
+#[cfg(not(feature = "serial"))]
+#[test]
+fn test_par_batch_verify() -> Result<()> {
+    type MP = MerklePath<CurrentEnvironment, 8>;
+
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..50).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 8>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let root = merkle_tree.root();
+    let paths = leaves.iter().enumerate().map(|(i, leaf)| merkle_tree.prove(i, leaf)).collect::<Result<Vec<_>>>()?;
+
+    // Ensure an empty batch is valid.
+    assert!(MP::par_batch_verify(&[], &leaf_hasher, &path_hasher, root));
+
+    // Ensure the result matches the sequential check.
+    let mut batch = paths.iter().zip_eq(&leaves).collect::<Vec<_>>();
+    assert!(MP::par_batch_verify(&batch, &leaf_hasher, &path_hasher, root));
+    assert_eq!(
+        MP::batch_verify(&batch, &leaf_hasher, &path_hasher, root),
+        MP::par_batch_verify(&batch, &leaf_hasher, &path_hasher, root)
+    );
+
+    // Ensure a batch with one invalid path is invalid.
+    batch[25] = (&paths[25], &leaves[26]);
+    assert!(!MP::par_batch_verify(&batch, &leaf_hasher, &path_hasher, root));
+    assert_eq!(
+        MP::batch_verify(&batch, &leaf_hasher, &path_hasher, root),
+        MP::par_batch_verify(&batch, &leaf_hasher, &path_hasher, root)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_multi_proof() -> Result<()> {
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..100).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 10>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let root = merkle_tree.root();
+
+    for indices in [vec![0], vec![99], vec![4, 5], vec![0, 1, 2, 3], vec![97, 3, 40, 41, 63, 0, 99], (0..100).collect()]
+    {
+        let paths = indices.iter().map(|i| merkle_tree.prove(*i, &leaves[*i])).collect::<Result<Vec<_>>>()?;
+        let multi_proof = MerkleMultiProof::from_paths(&paths)?;
+
+        // Ensure the multi-proof stores fewer siblings than the individual paths, when they overlap.
+        assert!(multi_proof.siblings().len() <= paths.len() * 10);
+        if indices.len() > 1 {
+            assert!(multi_proof.siblings().len() < paths.len() * 10);
+        }
+
+        // Ensure the multi-proof verifies, for the leaves in any order.
+        let proven_leaves = indices.iter().map(|i| (*i as u64, leaves[*i].clone())).collect::<Vec<_>>();
+        assert!(multi_proof.verify_multi(&leaf_hasher, &path_hasher, root, &proven_leaves));
+        let reversed_leaves = proven_leaves.iter().rev().cloned().collect::<Vec<_>>();
+        assert!(multi_proof.verify_multi(&leaf_hasher, &path_hasher, root, &reversed_leaves));
+
+        // Ensure the multi-proof does not verify for the wrong root, or a modified leaf.
+        assert!(!multi_proof.verify_multi(&leaf_hasher, &path_hasher, &Field::rand(&mut rng), &proven_leaves));
+        let mut modified_leaves = proven_leaves.clone();
+        modified_leaves[0].1 = vec![Field::rand(&mut rng)];
+        assert!(!multi_proof.verify_multi(&leaf_hasher, &path_hasher, root, &modified_leaves));
+        // Ensure the multi-proof does not verify for a subset or a superset of the leaves.
+        assert!(!multi_proof.verify_multi(&leaf_hasher, &path_hasher, root, &proven_leaves[1..]));
+        if indices.len() < 100 {
+            let extra = (0..100).find(|i| !indices.contains(i)).unwrap();
+            let mut extended_leaves = proven_leaves.clone();
+            extended_leaves.push((extra as u64, leaves[extra].clone()));
+            assert!(!multi_proof.verify_multi(&leaf_hasher, &path_hasher, root, &extended_leaves));
+        }
+    }
+
+    // Ensure a multi-proof of all leaves stores only the padding siblings.
+    let paths = leaves.iter().enumerate().map(|(i, leaf)| merkle_tree.prove(i, leaf)).collect::<Result<Vec<_>>>()?;
+    let multi_proof = MerkleMultiProof::from_paths(&paths)?;
+    assert!(multi_proof.siblings().len() < 10);
+
+    // Ensure conflicting paths for the same leaf index fail.
+    let other_tree = MerkleTree::<CurrentEnvironment, LH, PH, 10>::new(&leaf_hasher, &path_hasher, &leaves[1..])?;
+    let conflicting = [paths[0].clone(), other_tree.prove(0, &leaves[1])?];
+    assert!(MerkleMultiProof::from_paths(&conflicting).is_err());
+    // Ensure an empty multi-proof fails.
+    assert!(MerkleMultiProof::<CurrentEnvironment, 10>::from_paths(&[]).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_parse() -> Result<()> {
+    type MP = MerklePath<CurrentEnvironment, 4>;
+
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..11).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 4>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+    for (i, leaf) in leaves.iter().enumerate() {
+        let expected = merkle_tree.prove(i, leaf)?;
+
+        // Ensure the string round-trips.
+        let string = expected.to_string();
+        assert!(string.starts_with(&format!("{{ {i}u64, [")));
+        assert_eq!(expected, MP::from_str(&string)?);
+        let input = format!("{string} rest");
+        let (remainder, candidate) = MP::parse(&input).unwrap();
+        assert_eq!(expected, candidate);
+        assert_eq!(" rest", remainder);
+
+        // Ensure the JSON round-trips.
+        let candidate: MP = serde_json::from_str(&serde_json::to_string(&expected)?)?;
+        assert_eq!(expected, candidate);
+    }
+
+    // Ensure whitespace is permitted.
+    let siblings = merkle_tree.prove(3, &leaves[3])?.siblings().to_vec();
+    let string = format!("{{3u64,[{}]}}", siblings.iter().map(|s| s.to_string()).collect::<Vec<_>>().join(","));
+    assert_eq!(merkle_tree.prove(3, &leaves[3])?, MP::from_str(&string)?);
+
+    // Ensure the leaf index bounds and the path length are enforced.
+    let join =
+        |siblings: &[Field<CurrentEnvironment>]| siblings.iter().map(|s| s.to_string()).collect::<Vec<_>>().join(", ");
+    assert!(MP::from_str(&format!("{{ 16u64, [{}] }}", join(&siblings))).is_err());
+    assert!(MP::from_str(&format!("{{ 3u64, [{}] }}", join(&siblings[1..]))).is_err());
+    assert!(MP::from_str(&format!("{{ 3u64, [{}, {}] }}", join(&siblings), siblings[0])).is_err());
+    assert!(MP::from_str("{ 3u64, [] }").is_err());
+    assert!(MP::from_str(&format!("{{ 3u64, [{}] }} rest", join(&siblings))).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_non_membership() -> Result<()> {
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    // Construct a tree with the sorted leaves 0, 10, ..., 90.
+    let leaf = |value: u64| vec![Field::<CurrentEnvironment>::from_u64(value)];
+    let leaves = (0..10).map(|i| leaf(i * 10)).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 8>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let root = merkle_tree.root();
+    let compare = |a: &Vec<Field<CurrentEnvironment>>, b: &Vec<Field<CurrentEnvironment>>| a.cmp(b);
+
+    // Prove the absence of 15, between the leaves 10 and 20.
+    let proof = NonMembershipProof::try_from((merkle_tree.prove(1, &leaves[1])?, merkle_tree.prove(2, &leaves[2])?))?;
+    let neighbors = (&leaves[1], &leaves[2]);
+    assert!(proof.verify(&leaf_hasher, &path_hasher, root, neighbors, &leaf(15), compare));
+
+    // Ensure the proof fails for a target that is present, or outside the gap.
+    assert!(!proof.verify(&leaf_hasher, &path_hasher, root, neighbors, &leaf(10), compare));
+    assert!(!proof.verify(&leaf_hasher, &path_hasher, root, neighbors, &leaf(20), compare));
+    assert!(!proof.verify(&leaf_hasher, &path_hasher, root, neighbors, &leaf(25), compare));
+    // Ensure the proof fails for neighbors that are not in the tree.
+    assert!(!proof.verify(&leaf_hasher, &path_hasher, root, (&leaf(11), &leaves[2]), &leaf(15), compare));
+    // Ensure the proof fails for the wrong root.
+    assert!(!proof.verify(&leaf_hasher, &path_hasher, &Field::from_u64(1), neighbors, &leaf(15), compare));
+
+    // Ensure non-adjacent leaf indices fail.
+    let result = NonMembershipProof::try_from((merkle_tree.prove(1, &leaves[1])?, merkle_tree.prove(3, &leaves[3])?));
+    assert!(result.is_err());
+    let result = NonMembershipProof::try_from((merkle_tree.prove(2, &leaves[2])?, merkle_tree.prove(1, &leaves[1])?));
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_non_membership_outside_the_leaves() -> Result<()> {
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    // Construct a tree with the sorted leaves 10, 20, ..., 90.
+    let leaf = |value: u64| vec![Field::<CurrentEnvironment>::from_u64(value)];
+    let leaves = (1..10).map(|i| leaf(i * 10)).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 8>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let compare = |a: &Vec<Field<CurrentEnvironment>>, b: &Vec<Field<CurrentEnvironment>>| a.cmp(b);
+
+    // Ensure no proof verifies for a target below the first leaf or above the last leaf.
+    for index in 0..leaves.len() - 1 {
+        let (low, high) =
+            (merkle_tree.prove(index, &leaves[index])?, merkle_tree.prove(index + 1, &leaves[index + 1])?);
+        let proof = NonMembershipProof::try_from((low, high))?;
+        let neighbors = (&leaves[index], &leaves[index + 1]);
+        for target in [leaf(5), leaf(95)] {
+            assert!(!proof.verify(&leaf_hasher, &path_hasher, merkle_tree.root(), neighbors, &target, compare));
+        }
+    }
+
+    // Construct the tree with the least and greatest leaves as sentinels at either end.
+    let leaves = [vec![leaf(0)], leaves, vec![vec![-Field::<CurrentEnvironment>::one()]]].concat();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 8>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+    // Ensure the sentinels bracket the targets below the first leaf and above the last leaf.
+    for (index, target) in [(0, leaf(5)), (leaves.len() - 2, leaf(95))] {
+        let (low, high) =
+            (merkle_tree.prove(index, &leaves[index])?, merkle_tree.prove(index + 1, &leaves[index + 1])?);
+        let proof = NonMembershipProof::try_from((low, high))?;
+        let neighbors = (&leaves[index], &leaves[index + 1]);
+        assert!(proof.verify(&leaf_hasher, &path_hasher, merkle_tree.root(), neighbors, &target, compare));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_shares_subtree_with() -> Result<()> {
+    let rng = &mut TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    // Construct a tree with 8 leaves.
+    let leaves = (0..8).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 3>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let path = |index: usize| merkle_tree.prove(index, &leaves[index]);
+    let (path_0, path_1, path_2, path_4) = (path(0)?, path(1)?, path(2)?, path(4)?);
+
+    // Ensure a path shares every subtree with itself.
+    (0..=4).for_each(|level| assert!(path_0.shares_subtree_with(&path_0, level)));
+    // Ensure sibling leaves share the subtrees from level 1.
+    assert!(!path_0.shares_subtree_with(&path_1, 0));
+    assert!(path_0.shares_subtree_with(&path_1, 1));
+    assert!(path_1.shares_subtree_with(&path_0, 3));
+    // Ensure leaves 0 and 2 share the subtrees from level 2.
+    assert!(!path_0.shares_subtree_with(&path_2, 1));
+    assert!(path_0.shares_subtree_with(&path_2, 2));
+    // Ensure leaves 0 and 4 only share the root.
+    assert!(!path_0.shares_subtree_with(&path_4, 2));
+    assert!(path_0.shares_subtree_with(&path_4, 3));
+    assert!(path_0.shares_subtree_with(&path_4, u8::MAX));
+
+    // Ensure reusing the siblings of leaf 0 for leaf 2 is detected.
+    let forged = MerklePath::<CurrentEnvironment, 3>::try_from((U64::new(2), path_0.siblings().to_vec()))?;
+    assert!(!forged.shares_subtree_with(&path_2, 1));
+    assert!(!forged.shares_subtree_with(&path_0, 1));
+    Ok(())
+}
+
+#[test]
+fn test_to_bits_and_from_bits() -> Result<()> {
+    let rng = &mut TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    // Construct a tree with 10 leaves.
+    let leaves = (0..10).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 4>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let expected_size = 64 + 4 * Field::<CurrentEnvironment>::size_in_bits();
+
+    for (index, leaf) in leaves.iter().enumerate() {
+        let path = merkle_tree.prove(index, leaf)?;
+
+        // Check the little-endian round trip.
+        let bits_le = path.to_bits_le();
+        assert_eq!(expected_size, bits_le.len());
+        assert_eq!(U64::<CurrentEnvironment>::new(index as u64).to_bits_le(), bits_le[..64]);
+        assert_eq!(path, MerklePath::from_bits_le(&bits_le)?);
+
+        // Check the big-endian round trip.
+        let bits_be = path.to_bits_be();
+        assert_eq!(expected_size, bits_be.len());
+        assert_eq!(path, MerklePath::from_bits_be(&bits_be)?);
+    }
+
+    // Ensure truncated and extended inputs fail.
+    let bits_le = merkle_tree.prove(0, &leaves[0])?.to_bits_le();
+    assert!(MerklePath::<CurrentEnvironment, 4>::from_bits_le(&bits_le[..bits_le.len() - 1]).is_err());
+    assert!(MerklePath::<CurrentEnvironment, 4>::from_bits_le(&[bits_le.as_slice(), &[false]].concat()).is_err());
+    assert!(MerklePath::<CurrentEnvironment, 4>::from_bits_le(&[]).is_err());
+    // Ensure a leaf index outside the tree fails.
+    let mut bits_le = bits_le;
+    bits_le[4] = true;
+    assert!(MerklePath::<CurrentEnvironment, 4>::from_bits_le(&bits_le).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_verify_with_domain() -> Result<()> {
+    let rng = &mut TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    // Construct a tree with 4 leaves.
+    let leaves = (0..4).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 2>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+    // Returns the level 1 nodes and the root of the tree, with each parent hash domain-separated by its level.
+    let leaf_hashes = leaves.iter().map(|leaf| leaf_hasher.hash_leaf(leaf)).collect::<Result<Vec<_>>>()?;
+    let compute_root = |domain: &dyn Fn(u8) -> Field<CurrentEnvironment>| {
+        let hash = |level: u8, left: &Field<CurrentEnvironment>, right: &Field<CurrentEnvironment>| {
+            path_hasher.hash_children(&domain(level), &path_hasher.hash_children(left, right)?)
+        };
+        let left = hash(0, &leaf_hashes[0], &leaf_hashes[1])?;
+        let right = hash(0, &leaf_hashes[2], &leaf_hashes[3])?;
+        Ok::<_, Error>((left, right, hash(1, &left, &right)?))
+    };
+
+    // Ensure an empty domain matches `verify`.
+    let empty = |_: u8| None;
+    for (index, leaf) in leaves.iter().enumerate() {
+        let path = merkle_tree.prove(index, leaf)?;
+        assert!(path.verify(&leaf_hasher, &path_hasher, merkle_tree.root(), leaf));
+        assert!(path.verify_with_domain(&leaf_hasher, &path_hasher, merkle_tree.root(), leaf, &empty));
+        let other_leaf = &leaves[(index + 1) % leaves.len()];
+        assert!(!path.verify(&leaf_hasher, &path_hasher, merkle_tree.root(), other_leaf));
+        assert!(!path.verify_with_domain(&leaf_hasher, &path_hasher, merkle_tree.root(), other_leaf, &empty));
+    }
+
+    // Ensure a zero domain is mixed in, so the paths of the tree without domain separation do not verify.
+    let zero = |_: u8| Some(Field::<CurrentEnvironment>::zero());
+    let path = merkle_tree.prove(1, &leaves[1])?;
+    assert!(!path.verify_with_domain(&leaf_hasher, &path_hasher, merkle_tree.root(), &leaves[1], &zero));
+    let (_, right, root) = compute_root(&|_| Field::zero())?;
+    assert_ne!(merkle_tree.root(), &root);
+    let path = MerklePath::<CurrentEnvironment, 2>::try_from((U64::new(1), vec![leaf_hashes[0], right]))?;
+    assert!(path.verify_with_domain(&leaf_hasher, &path_hasher, &root, &leaves[1], &zero));
+
+    // Compute the root of the tree, with a distinct domain on each level.
+    let (left, right, root) = compute_root(&|level| Field::from_u8(level + 1))?;
+    let domain = |level: u8| Some(Field::<CurrentEnvironment>::from_u8(level + 1));
+
+    // Ensure the domain-separated paths verify against the domain-separated root.
+    let path = MerklePath::<CurrentEnvironment, 2>::try_from((U64::new(2), vec![leaf_hashes[3], left]))?;
+    assert!(path.verify_with_domain(&leaf_hasher, &path_hasher, &root, &leaves[2], &domain));
+    let path = MerklePath::<CurrentEnvironment, 2>::try_from((U64::new(1), vec![leaf_hashes[0], right]))?;
+    assert!(path.verify_with_domain(&leaf_hasher, &path_hasher, &root, &leaves[1], &domain));
+
+    // Ensure mismatched domains, leaves, and roots fail.
+    let swapped = |level: u8| Some(Field::<CurrentEnvironment>::from_u8(2 - level));
+    assert!(!path.verify_with_domain(&leaf_hasher, &path_hasher, &root, &leaves[1], &swapped));
+    assert!(!path.verify_with_domain(&leaf_hasher, &path_hasher, &root, &leaves[1], &zero));
+    assert!(!path.verify_with_domain(&leaf_hasher, &path_hasher, &root, &leaves[1], &empty));
+    assert!(!path.verify_with_domain(&leaf_hasher, &path_hasher, &root, &leaves[0], &domain));
+    assert!(!path.verify_with_domain(&leaf_hasher, &path_hasher, merkle_tree.root(), &leaves[1], &domain));
+    Ok(())
+}
+
+#[test]
+fn test_path_directions() -> Result<()> {
+    let rng = &mut TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    // Construct a tree with 11 leaves.
+    let leaves = (0..11).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 4>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+    // Ensure the directions are the negated bits of the leaf index, in leaf-to-root order.
+    let path = merkle_tree.prove(6, &leaves[6])?;
+    assert_eq!(path.path_directions(), vec![true, false, false, true]);
+    for (index, leaf) in leaves.iter().enumerate() {
+        let path = merkle_tree.prove(index, leaf)?;
+        let directions = path.path_directions();
+        assert_eq!(4, directions.len());
+        assert_eq!(directions, path.iter_levels().map(|(indicator, _)| indicator).collect::<Vec<_>>());
+        assert!(directions.iter().enumerate().all(|(i, direction)| *direction == ((index >> i) & 1 == 0)));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_verify_with_leaf_hash() -> Result<()> {
+    let rng = &mut TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    // Construct a tree with 7 leaves.
+    let leaves = (0..7).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 3>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let root = merkle_tree.root();
+
+    // Compute the leaf hashes in a batch.
+    let leaf_hashes = leaves.iter().map(|leaf| leaf_hasher.hash_leaf(leaf)).collect::<Result<Vec<_>>>()?;
+    for (index, leaf) in leaves.iter().enumerate() {
+        let path = merkle_tree.prove(index, leaf)?;
+        // Ensure the precomputed leaf hash verifies, consistent with `verify`.
+        assert!(path.verify_with_leaf_hash(&path_hasher, root, &leaf_hashes[index]));
+        assert!(path.verify(&leaf_hasher, &path_hasher, root, leaf));
+        // Ensure the wrong leaf hash, or the wrong root, fails.
+        assert!(!path.verify_with_leaf_hash(&path_hasher, root, &leaf_hashes[(index + 1) % leaves.len()]));
+        assert!(!path.verify_with_leaf_hash(&path_hasher, &Field::zero(), &leaf_hashes[index]));
+    }
+    Ok(())
+}
+
+/// A path hasher with node hashes that are two field elements wide, for testing wide Merkle paths.
+#[derive(Clone)]
+struct WidePH(PH);
+
+impl PathHash for WidePH {
+    type Hash = Field<CurrentEnvironment>;
+
+    fn hash_children(&self, left: &Self::Hash, right: &Self::Hash) -> Result<Self::Hash> {
+        self.0.hash_children(left, right)
+    }
+
+    fn hash_wide_children<const WIDTH: usize>(
+        &self,
+        left: &[Self::Hash; WIDTH],
+        right: &[Self::Hash; WIDTH],
+    ) -> Result<[Self::Hash; WIDTH]> {
+        ensure!(WIDTH == 2, "This path hash only supports node hashes of 2 field elements");
+        let first = Hash::hash(&self.0, &[left[0], left[1], right[0], right[1]])?;
+        let second = Hash::hash(&self.0, &[first, left[1], right[1]])?;
+        let hash = [first, second];
+        Ok(std::array::from_fn(|i| hash[i]))
+    }
+}
+
+#[test]
+fn test_wide_merkle_path() -> Result<()> {
+    type MP = MerklePath<CurrentEnvironment, 2, 2>;
+
+    let rng = &mut TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    // Ensure a Merkle path of width 1 verifies as a wide Merkle path.
+    let leaves = (0..6).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 3>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    for (index, leaf) in leaves.iter().enumerate() {
+        let path = merkle_tree.prove(index, leaf)?;
+        let leaf_hash = [leaf_hasher.hash_leaf(leaf)?];
+        assert!(path.verify_wide(&path_hasher, &[*merkle_tree.root()], &leaf_hash));
+        assert!(!path.verify_wide(&path_hasher, &[Field::zero()], &leaf_hash));
+        assert_eq!([*merkle_tree.root()], path.compute_root_wide(&path_hasher, leaf_hash)?);
+    }
+
+    // Construct a tree of depth 2, with node hashes of width 2.
+    let wide_hasher = WidePH(path_hasher.clone());
+    let leaf_hashes = (0..4).map(|_| [Field::rand(rng), Field::rand(rng)]).collect::<Vec<_>>();
+    let left = wide_hasher.hash_wide_children(&leaf_hashes[0], &leaf_hashes[1])?;
+    let right = wide_hasher.hash_wide_children(&leaf_hashes[2], &leaf_hashes[3])?;
+    let root = wide_hasher.hash_wide_children(&left, &right)?;
+
+    // Ensure the wide Merkle path verifies.
+    let path = MP::try_from((U64::new(3), [leaf_hashes[2], left].concat()))?;
+    assert!(path.verify_wide(&wide_hasher, &root, &leaf_hashes[3]));
+    assert!(!path.verify_wide(&wide_hasher, &root, &leaf_hashes[2]));
+    assert!(!path.verify_wide(&wide_hasher, &left, &leaf_hashes[3]));
+    // Ensure a path hasher without support for the width fails.
+    assert!(!path.verify_wide(&path_hasher, &root, &leaf_hashes[3]));
+    assert!(matches!(path.compute_root_wide(&path_hasher, leaf_hashes[3]), Err(MerklePathError::PathHashFailed(_))));
+
+    // Ensure the wide Merkle path serializes with two fields per sibling.
+    let bytes = path.to_bytes_le()?;
+    assert_eq!(8 + 2 * 2 * Field::<CurrentEnvironment>::SIZE_IN_BYTES, bytes.len());
+    assert_eq!(MP::size_in_bytes(), bytes.len());
+    assert_eq!(path, MP::read_le(&bytes[..])?);
+    assert!(MP::read_le(&bytes[..bytes.len() - 1]).is_err());
+
+    // Ensure malformed wide Merkle paths fail.
+    assert!(MP::try_from((U64::new(4), [leaf_hashes[2], left].concat())).is_err());
+    assert!(MP::try_from((U64::new(3), left.to_vec())).is_err());
+    assert!(MP::try_from((U64::new(3), [leaf_hashes[2], left].concat()[1..].to_vec())).is_err());
+    assert!(MerklePath::<CurrentEnvironment, 2, 0>::try_from((U64::new(3), vec![])).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_verify_consecutive_range() -> Result<()> {
+    let rng = &mut TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    // Construct a tree with 13 leaves.
+    let leaves = (0..13).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 4>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let root = merkle_tree.root();
+
+    // Returns the border siblings for the range `[start, end)`.
+    let border_siblings = |start: usize, end: usize| -> Result<Vec<Field<CurrentEnvironment>>> {
+        let paths = (start..end).map(|index| merkle_tree.prove(index, &leaves[index])).collect::<Result<Vec<_>>>()?;
+        Ok(MerkleMultiProof::<CurrentEnvironment, 4>::from_paths(&paths)?.siblings().to_vec())
+    };
+
+    for (start, end) in [(0, 1), (0, 8), (4, 8), (3, 9), (5, 13), (12, 13), (0, 13)] {
+        let siblings = border_siblings(start, end)?;
+        let range = &leaves[start..end];
+        let verify = |start: u64, range: &[Vec<Field<CurrentEnvironment>>], siblings: &[Field<CurrentEnvironment>]| {
+            MerkleMultiProof::<CurrentEnvironment, 4>::verify_consecutive_range(
+                &leaf_hasher,
+                &path_hasher,
+                root,
+                start,
+                range,
+                siblings,
+            )
+        };
+        // Ensure the range verifies.
+        assert!(verify(start as u64, range, &siblings));
+        // Ensure the range fails with too few, or too many, border siblings.
+        if !siblings.is_empty() {
+            assert!(!verify(start as u64, range, &siblings[..siblings.len() - 1]));
+        }
+        assert!(!verify(start as u64, range, &[siblings.as_slice(), &[Field::zero()]].concat()));
+        // Ensure the range fails at a different start index.
+        assert!(!verify(start as u64 + 1, range, &siblings));
+    }
+
+    // Ensure an aligned subtree needs a single border sibling per level above it.
+    assert_eq!(1, border_siblings(0, 8)?.len());
+    assert_eq!(2, border_siblings(4, 8)?.len());
+
+    // Ensure empty, out of bounds, and overflowing ranges fail.
+    let verify_range = |start: u64, range: &[Vec<Field<CurrentEnvironment>>]| {
+        MerkleMultiProof::<CurrentEnvironment, 4>::verify_consecutive_range(
+            &leaf_hasher,
+            &path_hasher,
+            root,
+            start,
+            range,
+            &[],
+        )
+    };
+    assert!(!verify_range(0, &[]));
+    assert!(!verify_range(15, &leaves[..2]));
+    assert!(!verify_range(u64::MAX, &leaves[..2]));
+    Ok(())
+}
+
+#[test]
+fn test_extend_depth() -> Result<()> {
+    let rng = &mut TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    // Construct the same leaves in a tree of depth 2, and in a tree of depth 4.
+    let leaves = (0..4).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>();
+    let tree = MerkleTree::<CurrentEnvironment, LH, PH, 2>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let deeper_tree = MerkleTree::<CurrentEnvironment, LH, PH, 4>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+    for (index, leaf) in leaves.iter().enumerate() {
+        let path = tree.prove(index, leaf)?;
+        let expected = deeper_tree.prove(index, leaf)?;
+        // Ensure the extended path matches the path in the deeper tree.
+        let extended = path.clone().extend_depth::<4>(expected.siblings()[2..].to_vec())?;
+        assert_eq!(expected, extended);
+        assert!(extended.verify(&leaf_hasher, &path_hasher, deeper_tree.root(), leaf));
+        // Ensure extending to the same depth is a no-op.
+        assert_eq!(path, path.clone().extend_depth::<2>(vec![])?);
+        // Ensure the wrong number of extra siblings fails.
+        assert!(path.clone().extend_depth::<4>(expected.siblings()[3..].to_vec()).is_err());
+        assert!(path.clone().extend_depth::<3>(expected.siblings()[2..].to_vec()).is_err());
+        // Ensure a shallower depth fails.
+        assert!(path.extend_depth::<1>(vec![]).is_err());
+    }
+    Ok(())
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn test_zeroize() -> Result<()> {
+    use zeroize::Zeroize;
+
+    let rng = &mut TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    // Construct a tree with 5 leaves.
+    let leaves = (0..5).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 3>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+    // Ensure zeroizing the path clears the leaf index and the siblings.
+    let mut path = merkle_tree.prove(3, &leaves[3])?;
+    path.zeroize();
+    assert_eq!(0, *path.leaf_index());
+    assert!(path.siblings().is_empty());
+    assert!(!path.verify(&leaf_hasher, &path_hasher, merkle_tree.root(), &leaves[3]));
+    Ok(())
+}
+
+#[test]
+fn test_prove_by_index() -> Result<()> {
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..11).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 5>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+    // Ensure the path for each leaf index matches `prove`, and verifies against the root.
+    for (leaf_index, leaf) in leaves.iter().enumerate() {
+        let path = merkle_tree.prove_by_index(leaf_index as u64)?;
+        assert_eq!(merkle_tree.prove(leaf_index, leaf)?, path);
+        assert!(merkle_tree.verify(&path, merkle_tree.root(), leaf));
+    }
+
+    // Ensure an out-of-range leaf index fails.
+    assert!(merkle_tree.prove_by_index(11).is_err());
+    assert!(merkle_tree.prove_by_index(u64::MAX).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_new_matches_sequential_build() -> Result<()> {
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    // Sample enough leaves for the leaf and first path levels to be hashed in parallel.
+    let leaves = (0..301).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 10>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+    // Construct the same Merkle tree sequentially, one node at a time.
+    let empty_hash = path_hasher.hash_empty()?;
+    let empty_node_hash = path_hasher.hash_children(&empty_hash, &empty_hash)?;
+    let num_nodes = leaves.len().next_power_of_two() - 1;
+    let mut expected = vec![empty_hash; num_nodes + leaves.len() + leaves.len() % 2];
+    for (i, leaf) in leaves.iter().enumerate() {
+        expected[num_nodes + i] = leaf_hasher.hash_leaf(leaf)?;
+    }
+    for i in (0..num_nodes).rev() {
+        expected[i] = match expected.get(2 * i + 1) {
+            Some(left) => path_hasher.hash_children(left, &expected[2 * i + 2])?,
+            None => empty_node_hash,
+        };
+    }
+    // Pad the root with one empty level, as the leaves fill a tree of depth 9.
+    let expected_root = path_hasher.hash_children(&expected[0], &empty_hash)?;
+
+    // Ensure the root and every stored node are identical.
+    assert_eq!(&expected_root, merkle_tree.root());
+    assert_eq!(expected, merkle_tree.tree());
+    Ok(())
+}
+
+#[test]
+fn test_prove_many() -> Result<()> {
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..13).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 6>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let root = merkle_tree.root();
+
+    // Ensure the multi-proof matches one constructed from the individual paths, with duplicates removed.
+    let indices = [9, 2, 3, 12, 2];
+    let multi_proof = merkle_tree.prove_many(&indices)?;
+    let paths = [2, 3, 9, 12].map(|index| merkle_tree.prove_by_index(index)).into_iter().collect::<Result<Vec<_>>>()?;
+    assert_eq!(MerkleMultiProof::from_paths(&paths)?, multi_proof);
+    assert_eq!(&[2, 3, 9, 12], multi_proof.leaf_indices());
+
+    // Ensure the multi-proof verifies against the root of the Merkle tree.
+    let proven_leaves = [2usize, 3, 9, 12].map(|index| (index as u64, leaves[index].clone()));
+    assert!(multi_proof.verify_multi(&leaf_hasher, &path_hasher, root, &proven_leaves));
+
+    // Ensure out-of-range leaf indices, and an empty set of leaf indices, are rejected.
+    assert!(merkle_tree.prove_many(&[0, 13]).is_err());
+    assert!(merkle_tree.prove_many(&[u64::MAX]).is_err());
+    assert!(merkle_tree.prove_many(&[]).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_bytes() -> Result<()> {
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    for num_leaves in [0, 1, 2, 7, 16, 33] {
+        let leaves = (0..num_leaves).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+        let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 8>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+        // Ensure the Merkle tree round-trips through bytes.
+        let bytes = merkle_tree.to_bytes_le()?;
+        let candidate =
+            MerkleTree::<CurrentEnvironment, LH, PH, 8>::read_le_with_hashers(&leaf_hasher, &path_hasher, &bytes[..])?;
+        assert_eq!(merkle_tree.root(), candidate.root());
+        assert_eq!(merkle_tree.tree(), candidate.tree());
+        assert_eq!(merkle_tree.number_of_leaves(), candidate.number_of_leaves());
+
+        // Ensure a truncated buffer fails.
+        assert!(
+            MerkleTree::<CurrentEnvironment, LH, PH, 8>::read_le_with_hashers(
+                &leaf_hasher,
+                &path_hasher,
+                &bytes[..bytes.len() - 1]
+            )
+            .is_err()
+        );
+        // Ensure a modified root fails.
+        let mut modified = bytes.clone();
+        modified[bytes.len() - Field::<CurrentEnvironment>::size_in_bytes()] ^= 1;
+        assert!(
+            MerkleTree::<CurrentEnvironment, LH, PH, 8>::read_le_with_hashers(
+                &leaf_hasher,
+                &path_hasher,
+                &modified[..]
+            )
+            .is_err()
+        );
+    }
+
+    // Ensure a number of leaves beyond the capacity of the Merkle tree fails.
+    let bytes = 257u64.to_bytes_le()?;
+    assert!(
+        MerkleTree::<CurrentEnvironment, LH, PH, 8>::read_le_with_hashers(&leaf_hasher, &path_hasher, &bytes[..])
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_read_write_le_many() -> Result<()> {
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..6).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 4>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let paths = (0..6).map(|index| merkle_tree.prove_by_index(index)).collect::<Result<Vec<_>>>()?;
+
+    // Write the paths, followed by trailing bytes.
+    let mut bytes = Vec::new();
+    MerklePath::write_le_many(&paths, &mut bytes)?;
+    assert_eq!(6 * MerklePath::<CurrentEnvironment, 4>::size_in_bytes(), bytes.len());
+    bytes.extend_from_slice(&[1, 2, 3]);
+
+    // Ensure the paths are read back, and the trailing bytes are not consumed.
+    let mut reader = &bytes[..];
+    assert_eq!(paths, MerklePath::<CurrentEnvironment, 4>::read_le_many(&mut reader, 6)?);
+    assert_eq!(&[1, 2, 3], reader);
+
+    // Ensure reading no paths consumes nothing.
+    let mut reader = &bytes[..];
+    assert!(MerklePath::<CurrentEnvironment, 4>::read_le_many(&mut reader, 0)?.is_empty());
+    assert_eq!(bytes.len(), reader.len());
+
+    // Ensure reading more paths than available fails.
+    assert!(MerklePath::<CurrentEnvironment, 4>::read_le_many(&bytes[..], 7).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_verify_root_comparison() -> Result<()> {
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..4).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 4>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let path = merkle_tree.prove(2, &leaves[2])?;
+    let root = *merkle_tree.root();
+
+    // Ensure the path verifies against its root, and fails against roots differing in a low or high limb.
+    assert!(path.verify(&leaf_hasher, &path_hasher, &root, &leaves[2]));
+    assert!(!path.verify(&leaf_hasher, &path_hasher, &(root + Field::one()), &leaves[2]));
+    // Offset the root by 2^240, which differs from it in the most significant limb.
+    let offset = Field::<CurrentEnvironment>::from_u128(1u128 << 120);
+    let high_bit_root = root + offset * offset;
+    assert_ne!(root, high_bit_root);
+    assert!(!path.verify(&leaf_hasher, &path_hasher, &high_bit_root, &leaves[2]));
+    assert!(!path.verify_with_leaf_hash(&path_hasher, &high_bit_root, &leaf_hasher.hash_leaf(&leaves[2])?));
+    Ok(())
+}
+
+#[test]
+fn test_leaf_index_bits() -> Result<()> {
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..12).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 5>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+    for leaf_index in 0..12u64 {
+        let path = merkle_tree.prove_by_index(leaf_index)?;
+        let bits = path.leaf_index_bits();
+        // Ensure there are exactly `DEPTH` bits, matching the low bits of the leaf index.
+        assert_eq!(5, bits.len());
+        assert_eq!(&leaf_index.to_bits_le()[..5], &bits[..]);
+        // Ensure the bits are the negation of the path directions used by verification.
+        assert!(bits.iter().zip_eq(path.path_directions()).all(|(bit, is_left)| *bit != is_left));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_prevalidate() -> Result<()> {
+    let mut rng = TestRng::default();
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..9).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 4>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+    // Ensure every path from the Merkle tree, including the last index within the depth, is well-formed.
+    for leaf_index in 0..9 {
+        assert!(merkle_tree.prove_by_index(leaf_index)?.prevalidate().is_ok());
+    }
+    let path = MerklePath::<CurrentEnvironment, 4>::try_from((U64::new(15), vec![Field::rand(&mut rng); 4]))?;
+    assert!(path.prevalidate().is_ok());
+
+    // Ensure a well-formed path with a failing leaf hasher is rejected by hashing, not by the structural checks.
+    let result = path.verify_with_reason(&FailingLeafHash, &path_hasher, merkle_tree.root(), &leaves[0]);
+    assert!(matches!(result, Err(MerklePathError::LeafHashFailed(_))));
+    Ok(())
+}
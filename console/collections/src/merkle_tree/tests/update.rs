@@ -375,6 +375,38 @@ fn test_merkle_tree_poseidon_update_is_consistent() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_merkle_tree_update_out_of_bounds() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut rng = TestRng::default();
+
+    // Construct a depth-3 Merkle tree with 5 leaves (leaving 3 leaves empty).
+    let leaves = (0..5).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let mut merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 3>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let root = *merkle_tree.root();
+
+    // Ensure updating a leaf index beyond the current number of leaves fails, and leaves the tree unchanged.
+    for leaf_index in [5, 7, 8, usize::MAX] {
+        assert!(merkle_tree.update(leaf_index, &vec![Uniform::rand(&mut rng)]).is_err());
+        assert_eq!(root, *merkle_tree.root());
+        assert_eq!(5, merkle_tree.number_of_leaves());
+    }
+
+    // Ensure updating the last leaf matches a fresh Merkle tree with the replacement in place.
+    let mut updated_leaves = leaves;
+    updated_leaves[4] = vec![Uniform::rand(&mut rng)];
+    merkle_tree.update(4, &updated_leaves[4])?;
+    let expected = MerkleTree::<CurrentEnvironment, LH, PH, 3>::new(&leaf_hasher, &path_hasher, &updated_leaves)?;
+    assert_eq!(expected.root(), merkle_tree.root());
+    assert_eq!(expected.tree(), merkle_tree.tree());
+    Ok(())
+}
+
 #[test]
 fn test_merkle_tree_depth_3_bhp() -> Result<()> {
     type LH = BHP1024<CurrentEnvironment>;
@@ -24,16 +24,31 @@ pub mod string_parser {
     //!   hex characters
     //! - an escape followed by whitespace consumes all whitespace between the
     //!   escape and the next non-whitespace character
+    //!
+    //! A raw string, of the form r"..." or r#"..."#, is parsed by the separate
+    //! `parse_raw_string`, rather than by `parse_string`. Its contents are taken
+    //! literally, without escape processing. As in Rust, the raw string is
+    //! terminated by a " followed by as many # as it was opened with.
+    //!
+    //! A byte string, of the form b"...", is parsed into bytes rather than a
+    //! string. Its contents must be ASCII, and it matches the same escape
+    //! sequences, except for \u{XXXX}, and additionally matches \xNN for any byte.
+    //!
+    //! The opt-in `parse_string_with_ascii_escapes` additionally matches ASCII code
+    //! points like Rust: \xNN, where NN is exactly 2 hex characters, up to \x7F.
+    //!
+    //! The opt-in blob parsers additionally match binary escapes of the form
+    //! \{XXXX}, where XXXX is an even-length hex string decoded to raw bytes.
 
     use nom::{
         branch::alt,
-        bytes::streaming::{is_not, take_while_m_n},
-        character::streaming::{char, multispace1},
-        combinator::{map, map_opt, map_res, value, verify},
+        bytes::streaming::{is_not, tag, take_until, take_while, take_while_m_n},
+        character::streaming::{char, multispace0, multispace1},
+        combinator::{complete, map, map_opt, map_res, opt, value, verify},
         error::{ErrorKind, FromExternalError, ParseError},
-        multi::fold_many0,
-        sequence::{delimited, preceded},
-        Err::Error,
+        multi::separated_list0,
+        sequence::{delimited, pair, preceded, separated_pair, terminated},
+        Err::{Error, Failure},
         IResult,
     };
 
@@ -108,13 +123,44 @@ pub mod string_parser {
         // `map_res` takes the result of a parser and applies a function that returns
         // a Result. In this case we take the hex bytes from parse_hex and attempt to
         // convert them to a u32.
-        let parse_u32 = map_res(parse_delimited_hex, move |hex| u32::from_str_radix(hex, 16));
+        let (remainder, code) = map_res(parse_delimited_hex, move |hex| u32::from_str_radix(hex, 16))(input)?;
+
+        // Not all u32 values are valid unicode code points, so we check the code point
+        // before converting it to a char. As the escape is well-formed, an invalid code
+        // point is a failure, rather than an error, so its cause is not lost by `alt`.
+        match code {
+            0xD800..=0xDFFF => Err(Failure(E::from_error_kind(
+                "Unicode escape contains a lone surrogate code point (U+D800 to U+DFFF)",
+                ErrorKind::Char,
+            ))),
+            0x110000.. => Err(Failure(E::from_error_kind(
+                "Unicode escape contains an out of range code point (above U+10FFFF)",
+                ErrorKind::Char,
+            ))),
+            // Note: This is safe, as the code point is neither a surrogate nor out of range.
+            _ => match std::char::from_u32(code) {
+                Some(c) => Ok((remainder, c)),
+                None => {
+                    Err(Failure(E::from_error_kind("Unicode escape contains an invalid code point", ErrorKind::Char)))
+                }
+            },
+        }
+    }
 
-        // map_opt is like map_res, but it takes an Option instead of a Result. If
-        // the function returns None, map_opt returns an error. In this case, because
-        // not all u32 values are valid unicode code points, we have to fallibly
-        // convert to char with from_u32.
-        map_opt(parse_u32, std::char::from_u32)(input)
+    /// Parse an ASCII sequence, of the form xNN, where NN is exactly 2 hexadecimal
+    /// numerals. As in Rust, only ASCII code points (up to x7F) are allowed, so that
+    /// the escape cannot be mistaken for a raw byte. We will combine this later with
+    /// parse_fragment in parse_string_with_ascii_escapes to parse sequences like \x41.
+    fn parse_ascii<'a, E>(input: &'a str) -> IResult<&'a str, char, E>
+    where
+        E: ParseError<&'a str> + FromExternalError<&'a str, std::num::ParseIntError>,
+    {
+        // Parse exactly 2 hexadecimal numerals, preceded by x.
+        let parse_hex = preceded(char('x'), take_while_m_n(2, 2, |c: char| c.is_ascii_hexdigit()));
+        // Convert the hex numerals to a u8.
+        let parse_u8 = map_res(parse_hex, move |hex| u8::from_str_radix(hex, 16));
+        // Ensure the u8 is an ASCII code point.
+        map_opt(parse_u8, |byte| byte.is_ascii().then_some(char::from(byte)))(input)
     }
 
     /// Parse an escaped character: \n, \t, \r, \u{00AC}, etc.
@@ -126,24 +172,29 @@ pub mod string_parser {
             char('\\'),
             // `alt` tries each parser in sequence, returning the result of
             // the first successful match
-            alt((
-                parse_unicode,
-                // The `value` parser returns a fixed value (the first argument) if its
-                // parser (the second argument) succeeds. In these cases, it looks for
-                // the marker characters (n, r, t, etc) and returns the matching
-                // character (\n, \r, \t, etc).
-                value('\n', char('n')),
-                value('\r', char('r')),
-                value('\t', char('t')),
-                value('\u{08}', char('b')),
-                value('\u{0C}', char('f')),
-                value('\\', char('\\')),
-                value('/', char('/')),
-                value('"', char('"')),
-            )),
+            alt((parse_unicode, parse_simple_escape)),
         )(input)
     }
 
+    /// Parse the marker character of a simple escape: n, r, t, b, f, \, / or ",
+    /// and return the escaped character. All of the escaped characters are ASCII.
+    fn parse_simple_escape<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, char, E> {
+        alt((
+            // The `value` parser returns a fixed value (the first argument) if its
+            // parser (the second argument) succeeds. In these cases, it looks for
+            // the marker characters (n, r, t, etc) and returns the matching
+            // character (\n, \r, \t, etc).
+            value('\n', char('n')),
+            value('\r', char('r')),
+            value('\t', char('t')),
+            value('\u{08}', char('b')),
+            value('\u{0C}', char('f')),
+            value('\\', char('\\')),
+            value('/', char('/')),
+            value('"', char('"')),
+        ))(input)
+    }
+
     /// Parse a backslash, followed by any amount of whitespace. This is used later
     /// to discard any escaped whitespace.
     fn parse_escaped_whitespace<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, &'a str, E> {
@@ -193,37 +244,312 @@ pub mod string_parser {
         ))(input)
     }
 
-    /// Parse a string. Use a loop of parse_fragment and push all of the fragments
-    /// into an output string.
+    /// The maximum number of code points in a string parsed by `parse_string`,
+    /// and the maximum number of bytes parsed by `parse_byte_string` and `parse_blob_bytes`.
+    pub const DEFAULT_MAX_STRING_LENGTH: usize = 1 << 20;
+
+    /// Parse a string of at most `DEFAULT_MAX_STRING_LENGTH` code points.
+    /// See `parse_string_bounded`.
     pub fn parse_string<'a, E>(input: &'a str) -> IResult<&'a str, String, E>
     where
         E: ParseError<&'a str> + FromExternalError<&'a str, std::num::ParseIntError>,
     {
-        // fold_many0 is the equivalent of iterator::fold. It runs a parser in a loop,
-        // and for each output value, calls a folding function on each output value.
-        let build_string = fold_many0(
-            // Our parser function– parses a single string fragment
-            parse_fragment,
-            // Our init value, an empty string
-            String::new,
-            // Our folding function. For each fragment, append the fragment to the
-            // string.
-            |mut string, fragment| {
-                match fragment {
-                    StringFragment::Literal(s) => string.push_str(s),
-                    StringFragment::EscapedChar(c) => string.push(c),
-                    StringFragment::EscapedWS => {}
-                }
-                string
-            },
-        );
+        parse_string_bounded(DEFAULT_MAX_STRING_LENGTH)(input)
+    }
+
+    /// Returns a parser for a string of at most `max_len` code points. Use a loop of
+    /// parse_fragment and push all of the fragments into an output string, failing with
+    /// `ErrorKind::TooLarge` as soon as the output string exceeds `max_len` code points.
+    ///
+    /// Exceeding the bound is a failure, rather than an error, so that an enclosing `alt`
+    /// does not backtrack and retry the oversized input with another parser.
+    pub fn parse_string_bounded<'a, E>(max_len: usize) -> impl FnMut(&'a str) -> IResult<&'a str, String, E>
+    where
+        E: ParseError<&'a str> + FromExternalError<&'a str, std::num::ParseIntError>,
+    {
+        let build_string = fold_string_bounded(parse_fragment, max_len);
 
         // Finally, parse the string. Note that, if `build_string` could accept a raw
         // " character, the closing delimiter " would never match. When using
         // `delimited` with a looping parser (like fold_many0), be sure that the
         // loop won't accidentally match your closing delimiter!
+        delimited(char('"'), build_string, char('"'))
+    }
+
+    /// Parse a string of at most `DEFAULT_MAX_STRING_LENGTH` code points, which may additionally
+    /// contain ASCII escapes of the form \xNN, up to \x7F. These are not accepted by `parse_string`.
+    pub fn parse_string_with_ascii_escapes<'a, E>(input: &'a str) -> IResult<&'a str, String, E>
+    where
+        E: ParseError<&'a str> + FromExternalError<&'a str, std::num::ParseIntError>,
+    {
+        // The ASCII escape is attempted first, as `parse_fragment` rejects the \x escape.
+        let parse_ascii_fragment =
+            alt((map(preceded(char('\\'), parse_ascii), StringFragment::EscapedChar), parse_fragment));
+
+        let build_string = fold_string_bounded(parse_ascii_fragment, DEFAULT_MAX_STRING_LENGTH);
+
         delimited(char('"'), build_string, char('"'))(input)
     }
+
+    /// Returns a parser that applies `parse_fragment` until it fails, and pushes each fragment
+    /// into an output string, as with `fold_many0`. Fails with `ErrorKind::TooLarge` as soon as
+    /// the output exceeds `max_len` code points, rather than continuing to allocate.
+    fn fold_string_bounded<'a, E, F>(
+        mut parse_fragment: F,
+        max_len: usize,
+    ) -> impl FnMut(&'a str) -> IResult<&'a str, String, E>
+    where
+        E: ParseError<&'a str>,
+        F: FnMut(&'a str) -> IResult<&'a str, StringFragment<'a>, E>,
+    {
+        move |mut input: &'a str| {
+            let mut string = String::new();
+            let mut len = 0;
+            loop {
+                let (remainder, fragment) = match parse_fragment(input) {
+                    Ok(output) => output,
+                    // A recoverable error ends the loop, as with `fold_many0`.
+                    Err(Error(_)) => return Ok((input, string)),
+                    Err(error) => return Err(error),
+                };
+                // Count the code points in the fragment.
+                len += match fragment {
+                    StringFragment::Literal(s) => s.chars().count(),
+                    StringFragment::EscapedChar(_) => 1,
+                    StringFragment::EscapedWS => 0,
+                };
+                // Ensure the string does not exceed the bound.
+                if len > max_len {
+                    return Err(Failure(E::from_error_kind(input, ErrorKind::TooLarge)));
+                }
+                match fragment {
+                    StringFragment::Literal(s) => string.push_str(s),
+                    StringFragment::EscapedChar(c) => string.push(c),
+                    StringFragment::EscapedWS => {}
+                }
+                input = remainder;
+            }
+        }
+    }
+
+    /// Parse a string from complete input, such as a whole file held in memory.
+    ///
+    /// The streaming `parse_string` returns `Incomplete` at the end of input, as more input may
+    /// follow. Instead, this parser treats the end of input as definitive, so that a missing
+    /// closing quote is reported as an error.
+    pub fn parse_string_complete<'a, E>(input: &'a str) -> IResult<&'a str, String, E>
+    where
+        E: ParseError<&'a str> + FromExternalError<&'a str, std::num::ParseIntError>,
+    {
+        complete(parse_string)(input)
+    }
+
+    /// Parse a raw string, of the form r"..." or r#"..."#, with any number of #.
+    /// The contents are taken literally, up to the first " followed by as many #
+    /// as the opening delimiter, so an embedded " does not terminate the string early.
+    ///
+    /// Raw strings are not part of the grammar accepted by `parse_string`, and must be opted into.
+    pub fn parse_raw_string<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, String, E> {
+        // Parse the opening delimiter, counting the #.
+        let (input, hashes) = delimited(char('r'), take_while(|c: char| c == '#'), char('"'))(input)?;
+        // Parse the contents, up to the matching closing delimiter.
+        let closing = format!("\"{hashes}");
+        let (input, contents) = terminated(take_until(closing.as_str()), tag(closing.as_str()))(input)?;
+
+        // Return an error if the contents contain an unsupported code point.
+        if contents.chars().any(is_char_unsupported) {
+            return Err(Error(E::from_error_kind("String literal contains invalid codepoint", ErrorKind::Char)));
+        }
+        Ok((input, contents.to_string()))
+    }
+
+    /// Parse an escaped byte: \n, \t, \r, \xFF, etc. Unlike parse_escaped_char,
+    /// \xNN may be any byte, and \u{XXXX} is not allowed, as bytes are not code points.
+    fn parse_escaped_byte<'a, E>(input: &'a str) -> IResult<&'a str, u8, E>
+    where
+        E: ParseError<&'a str> + FromExternalError<&'a str, std::num::ParseIntError>,
+    {
+        // Parse exactly 2 hexadecimal numerals, preceded by x, and convert them to a u8.
+        let parse_hex = preceded(char('x'), take_while_m_n(2, 2, |c: char| c.is_ascii_hexdigit()));
+        let parse_u8 = map_res(parse_hex, move |hex| u8::from_str_radix(hex, 16));
+
+        // The simple escapes are shared with parse_escaped_char, and are all ASCII.
+        preceded(char('\\'), alt((parse_u8, map(parse_simple_escape, |c| c as u8))))(input)
+    }
+
+    /// Parse a non-empty block of ASCII text that doesn't include \ or "
+    fn parse_byte_literal<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, &'a str, E> {
+        // Ensure the block is non-empty, and only contains supported ASCII code points.
+        verify(is_not("\"\\"), |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii() && is_char_supported(c)))(
+            input,
+        )
+    }
+
+    /// A byte string fragment contains a fragment of a byte string being parsed: either
+    /// a non-empty Literal (a series of non-escaped ASCII characters), a single
+    /// parsed escaped byte, or a block of escaped whitespace.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum ByteFragment<'a> {
+        Literal(&'a str),
+        EscapedByte(u8),
+        EscapedWS,
+    }
+
+    /// Parse a byte string, of the form b"...", of at most `DEFAULT_MAX_STRING_LENGTH` bytes.
+    /// Use a loop of byte fragments, as in parse_string, and push all of the fragments into
+    /// an output byte vector.
+    pub fn parse_byte_string<'a, E>(input: &'a str) -> IResult<&'a str, Vec<u8>, E>
+    where
+        E: ParseError<&'a str> + FromExternalError<&'a str, std::num::ParseIntError>,
+    {
+        // Combine parse_byte_literal, parse_escaped_whitespace, and parse_escaped_byte into a ByteFragment.
+        let parse_byte_fragment = alt((
+            map(parse_byte_literal, ByteFragment::Literal),
+            map(parse_escaped_byte, ByteFragment::EscapedByte),
+            value(ByteFragment::EscapedWS, parse_escaped_whitespace),
+        ));
+
+        let build_bytes =
+            fold_bytes_bounded(parse_byte_fragment, DEFAULT_MAX_STRING_LENGTH, |bytes, fragment| match fragment {
+                ByteFragment::Literal(s) => bytes.extend_from_slice(s.as_bytes()),
+                ByteFragment::EscapedByte(byte) => bytes.push(byte),
+                ByteFragment::EscapedWS => {}
+            });
+
+        preceded(char('b'), delimited(char('"'), build_bytes, char('"')))(input)
+    }
+
+    /// Returns a parser that applies `parse_fragment` until it fails, and appends each fragment
+    /// to an output byte vector, as with `fold_many0`. Fails with `ErrorKind::TooLarge` as soon as
+    /// the output exceeds `max_len` bytes, rather than continuing to allocate.
+    fn fold_bytes_bounded<'a, O, E, F, G>(
+        mut parse_fragment: F,
+        max_len: usize,
+        mut append: G,
+    ) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<u8>, E>
+    where
+        E: ParseError<&'a str>,
+        F: FnMut(&'a str) -> IResult<&'a str, O, E>,
+        G: FnMut(&mut Vec<u8>, O),
+    {
+        move |mut input: &'a str| {
+            let mut bytes = Vec::new();
+            loop {
+                let (remainder, fragment) = match parse_fragment(input) {
+                    Ok(output) => output,
+                    // A recoverable error ends the loop, as with `fold_many0`.
+                    Err(Error(_)) => return Ok((input, bytes)),
+                    Err(error) => return Err(error),
+                };
+                append(&mut bytes, fragment);
+                // Ensure the output does not exceed the bound.
+                if bytes.len() > max_len {
+                    return Err(Failure(E::from_error_kind(input, ErrorKind::TooLarge)));
+                }
+                input = remainder;
+            }
+        }
+    }
+
+    /// Parse a binary escape, of the form \{XXXX}, where XXXX is an even number of
+    /// hexadecimal numerals, and return the decoded bytes.
+    fn parse_escaped_blob<'a, E>(input: &'a str) -> IResult<&'a str, Vec<u8>, E>
+    where
+        E: ParseError<&'a str> + FromExternalError<&'a str, std::num::ParseIntError>,
+    {
+        // Parse an even number of hexadecimal numerals, delimited by braces.
+        let parse_hex = verify(take_while(|c: char| c.is_ascii_hexdigit()), |hex: &str| hex.len() % 2 == 0);
+        let parse_delimited_hex = preceded(char('\\'), delimited(char('{'), parse_hex, char('}')));
+
+        // Decode each pair of hexadecimal numerals into a byte.
+        map_res(parse_delimited_hex, |hex: &str| {
+            (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16)).collect::<Result<Vec<_>, _>>()
+        })(input)
+    }
+
+    /// A blob fragment contains either a string fragment, or the bytes of a binary escape.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum BlobFragment<'a> {
+        String(StringFragment<'a>),
+        Bytes(Vec<u8>),
+    }
+
+    /// Parse a string that may contain binary escapes of the form \{XXXX}, and return its bytes,
+    /// of which there may be at most `DEFAULT_MAX_STRING_LENGTH`. Use a loop of parse_escaped_blob
+    /// and parse_fragment and push all of the fragments into an output byte vector.
+    pub fn parse_blob_bytes<'a, E>(input: &'a str) -> IResult<&'a str, Vec<u8>, E>
+    where
+        E: ParseError<&'a str> + FromExternalError<&'a str, std::num::ParseIntError>,
+    {
+        // The binary escape is attempted first, as `parse_fragment` rejects the \{ escape.
+        let parse_blob_fragment =
+            alt((map(parse_escaped_blob, BlobFragment::Bytes), map(parse_fragment, BlobFragment::String)));
+
+        let build_bytes =
+            fold_bytes_bounded(parse_blob_fragment, DEFAULT_MAX_STRING_LENGTH, |bytes, fragment| match fragment {
+                BlobFragment::String(StringFragment::Literal(s)) => bytes.extend_from_slice(s.as_bytes()),
+                BlobFragment::String(StringFragment::EscapedChar(c)) => {
+                    bytes.extend_from_slice(c.encode_utf8(&mut [0u8; 4]).as_bytes())
+                }
+                BlobFragment::String(StringFragment::EscapedWS) => {}
+                BlobFragment::Bytes(blob) => bytes.extend(blob),
+            });
+
+        delimited(char('"'), build_bytes, char('"'))(input)
+    }
+
+    /// Parse a string that may contain binary escapes of the form \{XXXX}.
+    /// The decoded bytes must form valid UTF-8.
+    pub fn parse_blob_string<'a, E>(input: &'a str) -> IResult<&'a str, String, E>
+    where
+        E: ParseError<&'a str> + FromExternalError<&'a str, std::num::ParseIntError>,
+    {
+        map_opt(parse_blob_bytes, |bytes| String::from_utf8(bytes).ok())(input)
+    }
+
+    /// Returns a parser that applies `parser` at the given nesting `depth`, for grammars that
+    /// recursively parse the contents of a string as further structures. The output of `parser`
+    /// is returned unchanged.
+    ///
+    /// A recursive parser passes `depth + 1` when it descends into a nested structure. Once `depth`
+    /// exceeds `max_depth`, this fails with `ErrorKind::TooLarge` rather than applying `parser`,
+    /// so that deeply nested input cannot exhaust the stack.
+    pub fn parse_string_guarded<'a, O, E, F>(
+        max_depth: usize,
+        depth: usize,
+        mut parser: F,
+    ) -> impl FnMut(&'a str) -> IResult<&'a str, O, E>
+    where
+        E: ParseError<&'a str>,
+        F: FnMut(&'a str) -> IResult<&'a str, O, E>,
+    {
+        move |input: &'a str| {
+            // Ensure the nesting depth does not exceed the maximum.
+            if depth > max_depth {
+                return Err(Failure(E::from_error_kind(input, ErrorKind::TooLarge)));
+            }
+            parser(input)
+        }
+    }
+
+    /// Parse a brace-delimited, comma-separated list of `"key": "value"` string pairs,
+    /// such as `{ "a": "b", "c": "d" }`. A trailing comma is allowed.
+    pub fn parse_string_kv_pairs<'a, E>(input: &'a str) -> IResult<&'a str, Vec<(String, String)>, E>
+    where
+        E: ParseError<&'a str> + FromExternalError<&'a str, std::num::ParseIntError>,
+    {
+        // Parse a single key-value pair, allowing whitespace around the colon.
+        let parse_pair = separated_pair(parse_string, delimited(multispace0, char(':'), multispace0), parse_string);
+        // Parse the key-value pairs, allowing whitespace around the commas.
+        let parse_pairs = separated_list0(delimited(multispace0, char(','), multispace0), parse_pair);
+
+        // Parse the braces, allowing an optional trailing comma after the last pair.
+        delimited(
+            pair(char('{'), multispace0),
+            terminated(parse_pairs, opt(pair(multispace0, char(',')))),
+            pair(multispace0, char('}')),
+        )(input)
+    }
 }
 
 #[test]
@@ -245,3 +571,278 @@ fn test_parse_string() {
     assert!(parse_string_wrapper("\"hel\x1flo\"").is_err());
     assert!(parse_string_wrapper("\"hel\u{2069}lo\"").is_err());
 }
+
+#[test]
+fn test_parse_unicode_escape() {
+    fn parse_string_wrapper(input: &str) -> crate::ParserResult<String> {
+        string_parser::parse_string(input)
+    }
+
+    // Returns the error message for the given input.
+    fn error_message(input: &str) -> String {
+        match parse_string_wrapper(input) {
+            Err(nom::Err::Failure(error)) => error.errors[0].0.to_string(),
+            result => panic!("Expected a failure, found {result:?}"),
+        }
+    }
+
+    // tests some correct unicode escapes:
+    assert_eq!(("", String::from("A")), parse_string_wrapper("\"\\u{41}\"").unwrap());
+    assert_eq!(("", String::from("\u{D7FF}\u{E000}")), parse_string_wrapper("\"\\u{D7FF}\\u{E000}\"").unwrap());
+    assert_eq!(("", String::from("\u{10FFFF}")), parse_string_wrapper("\"\\u{10FFFF}\"").unwrap());
+
+    // test rejection of lone surrogates:
+    assert!(error_message("\"\\u{D800}\"").contains("lone surrogate"));
+    assert!(error_message("\"\\u{dfff}\"").contains("lone surrogate"));
+    assert!(error_message("\"a\\u{DC00}b\"").contains("lone surrogate"));
+
+    // test rejection of out of range code points:
+    assert!(error_message("\"\\u{110000}\"").contains("out of range"));
+    assert!(error_message("\"\\u{FFFFFF}\"").contains("out of range"));
+}
+
+#[test]
+fn test_parse_ascii_escape() {
+    fn parse_string_wrapper(input: &str) -> crate::ParserResult<String> {
+        string_parser::parse_string_with_ascii_escapes(input)
+    }
+
+    // tests some correct ASCII escapes:
+    assert_eq!(("", String::from("A")), parse_string_wrapper("\"\\x41\"").unwrap());
+    assert_eq!(("", String::from("aBc\n~")), parse_string_wrapper("\"a\\x42c\\x0a\\x7E\"").unwrap());
+    assert_eq!(("", String::from("\x7f0")), parse_string_wrapper("\"\\x7f0\"").unwrap());
+
+    // test rejection of malformed ASCII escapes:
+    assert!(parse_string_wrapper("\"\\xZZ\"").is_err());
+    assert!(parse_string_wrapper("\"\\x4\"").is_err());
+    assert!(parse_string_wrapper("\"\\x\"").is_err());
+    assert!(parse_string_wrapper("\"\\X41\"").is_err());
+
+    // test rejection of non-ASCII code points:
+    assert!(parse_string_wrapper("\"\\x80\"").is_err());
+    assert!(parse_string_wrapper("\"\\xff\"").is_err());
+
+    // tests the other escapes are still accepted:
+    assert_eq!(("", String::from("\u{e9}\n\"A")), parse_string_wrapper("\"\\u{e9}\\n\\\"\\x41\"").unwrap());
+
+    // test the standard string parser does not accept ASCII escapes:
+    assert!(string_parser::parse_string::<nom::error::VerboseError<&str>>("\"\\x41\"").is_err());
+}
+
+#[test]
+fn test_parse_raw_string() {
+    fn parse_string_wrapper(input: &str) -> crate::ParserResult<String> {
+        string_parser::parse_raw_string(input)
+    }
+
+    // tests some correct raw strings:
+    assert_eq!(("", String::from("")), parse_string_wrapper("r\"\"").unwrap());
+    assert_eq!(("", String::from("a\\d+\\n")), parse_string_wrapper("r\"a\\d+\\n\"").unwrap());
+    assert_eq!((" rest", String::from("abc")), parse_string_wrapper("r#\"abc\"# rest").unwrap());
+
+    // tests embedded quotes, which do not terminate a raw string with more #:
+    assert_eq!(("", String::from("say \"hi\"")), parse_string_wrapper("r#\"say \"hi\"\"#").unwrap());
+    assert_eq!(("", String::from("a\"#b")), parse_string_wrapper("r##\"a\"#b\"##").unwrap());
+    assert_eq!(("#", String::from("a")), parse_string_wrapper("r\"a\"#").unwrap());
+
+    // test rejection of unterminated raw strings:
+    assert!(parse_string_wrapper("r#\"abc\"").is_err());
+    assert!(parse_string_wrapper("r\"abc").is_err());
+    assert!(parse_string_wrapper("r#abc\"#").is_err());
+
+    // test rejection of disallowed characters:
+    assert!(parse_string_wrapper("r\"hel\x08lo\"").is_err());
+    assert!(parse_string_wrapper("r#\"hel\u{2069}lo\"#").is_err());
+
+    // test the standard string parser does not accept raw strings:
+    for input in ["r\"abc\"", "r#\"abc\"#", "r", "r#"] {
+        assert!(matches!(
+            string_parser::parse_string::<nom::error::VerboseError<&str>>(input),
+            Err(nom::Err::Error(_))
+        ));
+    }
+}
+
+#[test]
+fn test_parse_string_bounded() {
+    fn parse_string_bounded_wrapper(input: &str, max_len: usize) -> crate::ParserResult<String> {
+        string_parser::parse_string_bounded(max_len)(input)
+    }
+
+    // tests strings within the bound, counting code points rather than bytes:
+    assert_eq!(("", String::from("")), parse_string_bounded_wrapper("\"\"", 0).unwrap());
+    assert_eq!(("", String::from("abc")), parse_string_bounded_wrapper("\"abc\"", 3).unwrap());
+    assert_eq!(("", String::from("\u{e9}\u{4141}")), parse_string_bounded_wrapper("\"\u{e9}\u{4141}\"", 2).unwrap());
+    assert_eq!(("", String::from("a\nb")), parse_string_bounded_wrapper("\"a\\nb\"", 3).unwrap());
+    assert_eq!(("", String::from("ab")), parse_string_bounded_wrapper("\"a\\   b\"", 2).unwrap());
+
+    // test rejection of strings exceeding the bound:
+    assert!(parse_string_bounded_wrapper("\"abcd\"", 3).is_err());
+    assert!(parse_string_bounded_wrapper("\"\u{e9}\u{4141}\"", 1).is_err());
+    assert!(parse_string_bounded_wrapper("\"abc\\n\"", 3).is_err());
+    assert!(parse_string_bounded_wrapper("\"a\"", 0).is_err());
+
+    // test the bound is enforced before the closing quote is reached:
+    let input = format!("\"{}", "a\\n".repeat(100));
+    assert!(matches!(parse_string_bounded_wrapper(&input, 10), Err(nom::Err::Failure(_))));
+    assert!(matches!(parse_string_bounded_wrapper(&input, 200), Err(nom::Err::Incomplete(_))));
+
+    // test exceeding the bound is a failure, so that `alt` does not fall back to another parser:
+    let mut parse_alt = nom::branch::alt((string_parser::parse_string_bounded(3), string_parser::parse_string));
+    assert_eq!(("", String::from("abc")), parse_alt("\"abc\"").unwrap());
+    assert!(matches!(parse_alt("\"abcd\""), Err(nom::Err::Failure(nom::error::VerboseError { .. }))));
+}
+
+#[test]
+fn test_parse_string_complete() {
+    fn parse_string_complete_wrapper(input: &str) -> crate::ParserResult<String> {
+        string_parser::parse_string_complete(input)
+    }
+
+    // tests some correct string literals:
+    assert_eq!(("", String::from("abc")), parse_string_complete_wrapper("\"abc\"").unwrap());
+    assert_eq!((" rest", String::from("a\n")), parse_string_complete_wrapper("\"a\\n\" rest").unwrap());
+
+    // test an unterminated string is an error, rather than incomplete:
+    for input in ["\"abc", "\"abc\\", "\"\\u{41", ""] {
+        assert!(matches!(parse_string_complete_wrapper(input), Err(nom::Err::Error(_))));
+        assert!(!matches!(
+            string_parser::parse_string::<nom::error::VerboseError<&str>>(input),
+            Ok(_) | Err(nom::Err::Error(_))
+        ));
+    }
+}
+
+#[test]
+fn test_parse_byte_string() {
+    fn parse_byte_string_wrapper(input: &str) -> crate::ParserResult<Vec<u8>> {
+        string_parser::parse_byte_string(input)
+    }
+
+    // tests some correct byte strings:
+    assert_eq!(("", vec![]), parse_byte_string_wrapper("b\"\"").unwrap());
+    assert_eq!((" rest", b"abc".to_vec()), parse_byte_string_wrapper("b\"abc\" rest").unwrap());
+    assert_eq!(("", b"a\n\t\"\\/".to_vec()), parse_byte_string_wrapper("b\"a\\n\\t\\\"\\\\\\/\"").unwrap());
+    assert_eq!(("", vec![0x00, 0x41, 0x80, 0xff]), parse_byte_string_wrapper("b\"\\x00\\x41\\x80\\xFf\"").unwrap());
+    assert_eq!(("", b"ab".to_vec()), parse_byte_string_wrapper("b\"a\\\n   b\"").unwrap());
+
+    // test rejection of unicode escapes and non-ASCII literals:
+    assert!(parse_byte_string_wrapper("b\"\\u{41}\"").is_err());
+    assert!(parse_byte_string_wrapper("b\"\u{e9}\"").is_err());
+    assert!(parse_byte_string_wrapper("b\"a\u{4141}b\"").is_err());
+
+    // test rejection of malformed input:
+    assert!(parse_byte_string_wrapper("b\"\\xZZ\"").is_err());
+    assert!(parse_byte_string_wrapper("b\"\\x4\"").is_err());
+    assert!(parse_byte_string_wrapper("b\"hel\x08lo\"").is_err());
+    assert!(parse_byte_string_wrapper("\"abc\"").is_err());
+    assert!(parse_byte_string_wrapper("b'abc'").is_err());
+
+    // test the maximum length is enforced, and exceeding it is a failure:
+    let max_len = string_parser::DEFAULT_MAX_STRING_LENGTH;
+    let bytes = parse_byte_string_wrapper(&format!("b\"{}\"", "a".repeat(max_len))).unwrap().1;
+    assert_eq!(max_len, bytes.len());
+    let too_long = format!("b\"{}\\x41\"", "a".repeat(max_len));
+    assert!(matches!(parse_byte_string_wrapper(&too_long), Err(nom::Err::Failure(_))));
+}
+
+#[test]
+fn test_parse_string_kv_pairs() {
+    fn parse_string_kv_pairs_wrapper(input: &str) -> crate::ParserResult<Vec<(String, String)>> {
+        string_parser::parse_string_kv_pairs(input)
+    }
+
+    let pair = |key: &str, value: &str| (key.to_string(), value.to_string());
+
+    // tests an empty object:
+    assert_eq!(("", vec![]), parse_string_kv_pairs_wrapper("{}").unwrap());
+    assert_eq!(("", vec![]), parse_string_kv_pairs_wrapper("{ }").unwrap());
+
+    // tests a single pair:
+    assert_eq!(("", vec![pair("a", "b")]), parse_string_kv_pairs_wrapper("{\"a\": \"b\"}").unwrap());
+    assert_eq!((" rest", vec![pair("a", "b")]), parse_string_kv_pairs_wrapper("{ \"a\" : \"b\", } rest").unwrap());
+
+    // tests multiple pairs:
+    assert_eq!(
+        ("", vec![pair("a", "b"), pair("c\n", ""), pair("a", "d")]),
+        parse_string_kv_pairs_wrapper("{\"a\": \"b\",\"c\\n\":\"\" , \"a\": \"d\",}").unwrap()
+    );
+
+    // test rejection of malformed input:
+    assert!(parse_string_kv_pairs_wrapper("{\"a\" \"b\"}").is_err());
+    assert!(parse_string_kv_pairs_wrapper("{\"a\": \"b\" \"c\": \"d\"}").is_err());
+    assert!(parse_string_kv_pairs_wrapper("{\"a\": \"b\",,}").is_err());
+    assert!(parse_string_kv_pairs_wrapper("\"a\": \"b\"").is_err());
+}
+
+#[test]
+fn test_parse_blob_string() {
+    fn parse_blob_string_wrapper(input: &str) -> crate::ParserResult<String> {
+        string_parser::parse_blob_string(input)
+    }
+    fn parse_blob_bytes_wrapper(input: &str) -> crate::ParserResult<Vec<u8>> {
+        string_parser::parse_blob_bytes(input)
+    }
+
+    // tests some correct binary escapes:
+    assert_eq!(("", String::from("A")), parse_blob_string_wrapper("\"\\{41}\"").unwrap());
+    assert_eq!(("", String::from("")), parse_blob_string_wrapper("\"\\{}\"").unwrap());
+    assert_eq!(("", String::from("xAbc\n")), parse_blob_string_wrapper("\"x\\{4162}c\\n\"").unwrap());
+    assert_eq!(("", String::from("\u{e9}")), parse_blob_string_wrapper("\"\\{c3A9}\"").unwrap());
+
+    // tests a multi-byte blob, which is not valid UTF-8:
+    assert_eq!(("", vec![0x00, 0xff, 0x10, 0x41, b'z']), parse_blob_bytes_wrapper("\"\\{00ff1041}z\"").unwrap());
+    assert!(parse_blob_string_wrapper("\"\\{00ff1041}z\"").is_err());
+
+    // test rejection of malformed binary escapes:
+    assert!(parse_blob_bytes_wrapper("\"\\{414}\"").is_err());
+    assert!(parse_blob_bytes_wrapper("\"\\{4g}\"").is_err());
+    assert!(parse_blob_bytes_wrapper("\"\\{41\"").is_err());
+
+    // test the standard string parser does not accept binary escapes:
+    assert!(string_parser::parse_string::<nom::error::VerboseError<&str>>("\"\\{41}\"").is_err());
+
+    // test the maximum length is enforced in bytes, and exceeding it is a failure:
+    let max_len = string_parser::DEFAULT_MAX_STRING_LENGTH;
+    let bytes = parse_blob_bytes_wrapper(&format!("\"\\{{{}}}\"", "ff".repeat(max_len))).unwrap().1;
+    assert_eq!(max_len, bytes.len());
+    let too_long = format!("\"{}\\{{41}}\"", "a".repeat(max_len));
+    assert!(matches!(parse_blob_bytes_wrapper(&too_long), Err(nom::Err::Failure(_))));
+    assert!(matches!(parse_blob_string_wrapper(&too_long), Err(nom::Err::Failure(_))));
+}
+
+#[test]
+fn test_parse_string_guarded() {
+    use nom::{branch::alt, character::complete::char, sequence::delimited};
+
+    // Parses a string literal, nested in any number of brackets, and returns its contents.
+    fn parse_nested(input: &str, depth: usize, max_depth: usize) -> crate::ParserResult<String> {
+        string_parser::parse_string_guarded(max_depth, depth, |input| {
+            alt((
+                string_parser::parse_string,
+                delimited(char('['), |input| parse_nested(input, depth + 1, max_depth), char(']')),
+            ))(input)
+        })(input)
+    }
+
+    // Wraps the given input in the given number of brackets.
+    fn nest(input: &str, depth: usize) -> String {
+        format!("{}{input}{}", "[".repeat(depth), "]".repeat(depth))
+    }
+
+    // tests the parsed value is unchanged, including a string whose contents are a string literal:
+    for input in ["\"abc\"", "\"\\\"abc\\\"\"", "\"a\\nb\" rest"] {
+        assert_eq!(string_parser::parse_string(input), parse_nested(input, 0, 0));
+    }
+    assert_eq!(("", String::from("\"abc\"")), parse_nested("\"\\\"abc\\\"\"", 0, 4).unwrap());
+
+    // tests input nested to the maximum depth:
+    for max_depth in 0..8 {
+        let input = nest("\"a\\\"b\"", max_depth);
+        assert_eq!(("", String::from("a\"b")), parse_nested(&input, 0, max_depth).unwrap());
+
+        // test rejection of input nested one level deeper, as a failure:
+        let input = nest("\"a\\\"b\"", max_depth + 1);
+        assert!(matches!(parse_nested(&input, 0, max_depth), Err(nom::Err::Failure(_))));
+    }
+}
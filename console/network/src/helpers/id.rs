@@ -76,7 +76,7 @@ impl<F: FieldTrait, const PREFIX: u16> Bech32ID<F> for AleoID<F, PREFIX> {
 
     #[inline]
     fn size_in_bytes() -> usize {
-        (F::size_in_bits() + 7) / 8
+        F::size_in_bytes()
     }
 
     #[inline]
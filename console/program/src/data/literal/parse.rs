@@ -112,4 +112,24 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_string_with_invalid_unicode_escape() -> Result<()> {
+        let (remainder, candidate) = Literal::<CurrentNetwork>::parse("\"\\u{10FFFF}\"")?;
+        assert!(matches!(candidate, Literal::String(_)));
+        assert_eq!("", remainder);
+
+        // An invalid code point is a failure in the string parser, which stops the `alt` early.
+        // As no other literal begins with a quote, it is rejected, as with any other malformed string.
+        for string in ["\"\\u{D800}\"", "\"\\u{DFFF}\"", "\"\\u{110000}\"", "\"\\q\""] {
+            assert!(Literal::<CurrentNetwork>::parse(string).is_err());
+            assert!(Literal::<CurrentNetwork>::from_str(string).is_err());
+            assert!(Literal::<CurrentNetwork>::from_str(&format!("{string}u8")).is_err());
+        }
+
+        // Ensure the cause of an invalid code point is reported.
+        let error = Literal::<CurrentNetwork>::from_str("\"\\u{D800}\"").unwrap_err().to_string();
+        assert!(error.contains("lone surrogate"), "{error}");
+        Ok(())
+    }
 }
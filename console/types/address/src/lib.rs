@@ -30,6 +30,8 @@ mod to_field;
 mod to_fields;
 mod to_group;
 
+pub use parse::parse_address_string;
+
 pub use snarkvm_console_network_environment::prelude::*;
 pub use snarkvm_console_types_boolean::Boolean;
 pub use snarkvm_console_types_field::Field;
@@ -14,6 +14,11 @@
 
 use super::*;
 
+use nom::{
+    error::{FromExternalError, ParseError},
+    IResult,
+};
+
 static ADDRESS_PREFIX: &str = "aleo";
 
 impl<E: Environment> Parser for Address<E> {
@@ -33,6 +38,19 @@ impl<E: Environment> Parser for Address<E> {
     }
 }
 
+/// Parses a quoted string literal into an address, i.e. `"aleo1..."`.
+///
+/// If the input is not a string literal, the error is reported by the string parser.
+/// If the string literal is not a valid address, the error from `Address::from_str` is
+/// passed to the parser error through `FromExternalError`, with `ErrorKind::MapRes`.
+pub fn parse_address_string<'a, N: Environment, E>(string: &'a str) -> IResult<&'a str, Address<N>, E>
+where
+    E: ParseError<&'a str> + FromExternalError<&'a str, std::num::ParseIntError> + FromExternalError<&'a str, Error>,
+{
+    // Parse the string literal, and then parse the address from its contents.
+    map_res(string_parser::parse_string, |literal: String| Address::<N>::from_str(&literal))(string)
+}
+
 impl<E: Environment> FromStr for Address<E> {
     type Err = Error;
 
@@ -78,6 +96,7 @@ impl<E: Environment> Display for Address<E> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use nom::error::ErrorKind;
     use snarkvm_console_network_environment::Console;
 
     type CurrentEnvironment = Console;
@@ -105,6 +124,75 @@ mod tests {
         Ok(())
     }
 
+    /// An error that keeps the cause of an invalid address, which `VerboseError` discards.
+    #[derive(Debug)]
+    enum AddressStringError<'a> {
+        Nom(&'a str, ErrorKind),
+        Address(String),
+    }
+
+    impl<'a> ParseError<&'a str> for AddressStringError<'a> {
+        fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+            Self::Nom(input, kind)
+        }
+
+        fn append(_: &'a str, _: ErrorKind, other: Self) -> Self {
+            other
+        }
+    }
+
+    impl<'a> FromExternalError<&'a str, std::num::ParseIntError> for AddressStringError<'a> {
+        fn from_external_error(input: &'a str, kind: ErrorKind, _: std::num::ParseIntError) -> Self {
+            Self::Nom(input, kind)
+        }
+    }
+
+    impl<'a> FromExternalError<&'a str, Error> for AddressStringError<'a> {
+        fn from_external_error(_: &'a str, _: ErrorKind, error: Error) -> Self {
+            Self::Address(error.to_string())
+        }
+    }
+
+    #[test]
+    fn test_parse_address_string() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new address.
+            let expected = Address::<CurrentEnvironment>::rand(&mut rng);
+
+            // Check a valid quoted address.
+            let input = format!("\"{expected}\"");
+            let result: ParserResult<Address<CurrentEnvironment>> = parse_address_string(&input);
+            let (remainder, candidate) = result.unwrap();
+            assert_eq!(expected, candidate);
+            assert_eq!("", remainder);
+        }
+
+        // Ensure a string literal with an invalid address fails with the address error.
+        match parse_address_string::<CurrentEnvironment, AddressStringError>("\"aleo1invalid\"") {
+            Err(nom::Err::Error(AddressStringError::Address(message))) => {
+                assert_eq!("Invalid account address length: found 12, expected 63", message)
+            }
+            result => panic!("Expected an address error, found {result:?}"),
+        }
+
+        // Ensure a string literal with a corrupted address fails with the address error.
+        let address = Address::<CurrentEnvironment>::rand(&mut rng).to_string();
+        let input = format!("\"{}\"", address.replacen("aleo", "aleb", 1));
+        match parse_address_string::<CurrentEnvironment, AddressStringError>(&input) {
+            Err(nom::Err::Error(AddressStringError::Address(message))) => assert!(message.contains("checksum")),
+            result => panic!("Expected an address error, found {result:?}"),
+        }
+
+        // Ensure a non-string input fails as a string literal error.
+        match parse_address_string::<CurrentEnvironment, AddressStringError>("aleo1invalid") {
+            Err(nom::Err::Error(AddressStringError::Nom("aleo1invalid", ErrorKind::Char))) => {}
+            result => panic!("Expected a string literal error, found {result:?}"),
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_string() -> Result<()> {
         let mut rng = TestRng::default();
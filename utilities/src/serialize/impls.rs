@@ -633,6 +633,22 @@ mod test {
     use super::*;
     use crate::{deserialize_vec_without_len, serialize_vec_without_len, serialized_vec_size_without_len};
 
+    #[test]
+    fn test_take_from_value_errors() {
+        let mut value = serde_json::json!({ "round": 5, "author": "aleo" });
+
+        // Ensure a valid field is taken.
+        assert_eq!(5, u64::take_from_value::<serde_json::Value>(&mut value, "round").unwrap());
+
+        // Ensure a missing field is named in the error, along with the expected type.
+        let error = u64::take_from_value::<serde_json::Value>(&mut value, "timestamp").unwrap_err().to_string();
+        assert!(error.starts_with("The \"timestamp\" field is missing (expected u64)"), "{error}");
+
+        // Ensure an invalid field is named in the error, along with the expected type.
+        let error = u64::take_from_value::<serde_json::Value>(&mut value, "author").unwrap_err().to_string();
+        assert!(error.starts_with("The \"author\" field is invalid (expected u64)"), "{error}");
+    }
+
     fn test_serialize<T: PartialEq + std::fmt::Debug + CanonicalSerialize + CanonicalDeserialize>(data: T) {
         let combinations = [
             (Compress::No, Validate::No),
@@ -186,6 +186,8 @@ pub trait CanonicalDeserializeWithFlags: Sized {
 }
 
 /// A helper trait used to simplify value extraction.
+///
+/// On failure, the error names the field, and whether it is missing or could not be parsed as `Self`.
 pub trait DeserializeExt<'de>
 where
     Self: DeserializeOwned,
@@ -198,9 +200,14 @@ where
     T: DeserializeOwned,
 {
     fn take_from_value<D: Deserializer<'de>>(value: &mut serde_json::Value, field: &str) -> Result<Self, D::Error> {
+        // Retrieve the expected type, to name it in the error message.
+        let expected = std::any::type_name::<T>();
         serde_json::from_value(
-            value.get_mut(field).ok_or_else(|| de::Error::custom(format!("The \"{field}\" field is missing")))?.take(),
+            value
+                .get_mut(field)
+                .ok_or_else(|| de::Error::custom(format!("The \"{field}\" field is missing (expected {expected})")))?
+                .take(),
         )
-        .map_err(de::Error::custom)
+        .map_err(|error| de::Error::custom(format!("The \"{field}\" field is invalid (expected {expected}): {error}")))
     }
 }